@@ -26,6 +26,9 @@ fn main() {
     config
         .type_attribute(".", "#[serde(rename_all = \"SCREAMING_SNAKE_CASE\")]");
 
+    // Scenarios stored before spawn points existed have no such field
+    config.field_attribute("scenario.Scenario.spawn_points", "#[serde(default)]");
+
     // Compile with serde support
     config.compile_protos(&protos, &[proto_dir])
         .expect("Failed to compile proto files");
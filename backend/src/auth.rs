@@ -0,0 +1,32 @@
+use axum::http::{HeaderMap, StatusCode, header::AUTHORIZATION};
+use redis::{Connection, TypedCommands};
+use tracing::warn;
+
+/// Seconds a guest identity survives without being used.
+pub const TOKEN_TTL_SECS: u64 = 30 * 24 * 60 * 60;
+
+pub fn token_key(token: &str) -> String {
+    format!("token:{}", token)
+}
+
+/// The user a token was issued to. Every use extends the token's lifetime.
+pub fn resolve_token(redis: &mut Connection, token: &str) -> Option<String> {
+    let key = token_key(token);
+    let user_id = redis.get(&key).ok().flatten()?;
+    if let Err(e) = redis.expire(&key, TOKEN_TTL_SECS as i64) {
+        warn!("⚠️ Failed to refresh token expiry for {}: {}", user_id, e);
+    }
+    Some(user_id)
+}
+
+/// Token from an `Authorization: Bearer <token>` header.
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers.get(AUTHORIZATION)?.to_str().ok()?.strip_prefix("Bearer ")
+}
+
+/// Who is making an HTTP request. The id comes from the bearer token; any `user_id`
+/// in the request body is ignored.
+pub fn request_user(redis: &mut Connection, headers: &HeaderMap) -> Result<String, StatusCode> {
+    let token = bearer_token(headers).ok_or(StatusCode::UNAUTHORIZED)?;
+    resolve_token(redis, token).ok_or(StatusCode::UNAUTHORIZED)
+}
@@ -0,0 +1,104 @@
+pub mod artillery;
+pub mod combat;
+pub mod command;
+pub mod config;
+pub mod connection;
+pub mod engineering;
+pub mod morale;
+pub mod movement;
+pub mod objectives;
+pub mod outbox;
+pub mod simulation;
+pub mod spatial;
+pub mod state;
+pub mod supply;
+pub mod terrain;
+pub mod victory;
+pub mod visibility;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, mpsc};
+
+use crate::models::proto::{
+    BuildRequest, BuildUnitRequest, CommandRejectedEvent, CommandRejectionReason, FireMissionRequest, MoveUnitPathRequest,
+    MoveUnitRequest, StopUnitRequest, ws_server_message,
+};
+
+/// Fixed simulation step shared by every running session.
+pub const TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Commands forwarded from sockets and HTTP handlers into a session loop.
+#[derive(Debug)]
+pub enum GameCommand {
+    MoveUnit {
+        user_id: String,
+        request: MoveUnitRequest,
+    },
+    MoveUnitPath {
+        user_id: String,
+        request: MoveUnitPathRequest,
+    },
+    StopUnit {
+        user_id: String,
+        request: StopUnitRequest,
+    },
+    FireMission {
+        user_id: String,
+        request: FireMissionRequest,
+    },
+    Build {
+        user_id: String,
+        request: BuildRequest,
+    },
+    BuildUnit {
+        user_id: String,
+        request: BuildUnitRequest,
+    },
+    Surrender {
+        user_id: String,
+    },
+    /// The client lost track of the game and wants the full state of its side.
+    RequestSnapshot {
+        user_id: String,
+    },
+    /// The player's socket closed; they have the reconnect grace period to come back.
+    PlayerDisconnected {
+        user_id: String,
+    },
+    PlayerReconnected {
+        user_id: String,
+    },
+}
+
+pub type GameTx = mpsc::UnboundedSender<GameCommand>;
+
+/// Running session loops keyed by session id. Dropping a sender stops its loop.
+pub type Games = Arc<Mutex<HashMap<String, GameTx>>>;
+
+/// Why a player's command was refused; reported back as a `CommandRejectedEvent`.
+#[derive(Clone, Debug)]
+pub struct Rejection {
+    pub reason: CommandRejectionReason,
+    pub message: String,
+}
+
+impl Rejection {
+    pub fn new(reason: CommandRejectionReason, message: impl Into<String>) -> Self {
+        Self {
+            reason,
+            message: message.into(),
+        }
+    }
+
+    pub fn into_payload(self, session_id: &str, command: &str) -> ws_server_message::Payload {
+        ws_server_message::Payload::CommandRejected(CommandRejectedEvent {
+            session_id: session_id.to_string(),
+            command: command.to_string(),
+            reason: self.reason as i32,
+            message: self.message,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use crate::game::Rejection;
+use crate::game::combat::apply_damage;
+use crate::game::morale;
+use crate::game::outbox::Outbox;
+use crate::game::state::SessionState;
+use crate::game::visibility::side_can_see;
+use crate::models::proto::{
+    CommandRejectionReason, FireMissionLaunchedEvent, FireMissionRequest, Position, ShellImpactEvent,
+    UnitSide, ws_server_message,
+};
+use crate::utils::{haversine_distance, offset_position};
+
+/// A fire mission on its way to the target.
+#[derive(Clone, Debug)]
+pub struct Shell {
+    pub unit_id: String,
+    /// Where the shell lands, scatter already applied.
+    pub impact: Position,
+    pub damage: u32,
+    pub blast_radius_km: f64,
+    pub eta_secs: f64,
+}
+
+/// Fires a unit's indirect weapon at a map position. The target has to be inside the
+/// unit's fire support range and in sight of at least one friendly unit.
+pub fn fire_mission(state: &mut SessionState, req: &FireMissionRequest, outbox: &mut Outbox) -> Result<(), Rejection> {
+    let unit = state.units.get(&req.unit_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::UnitNotFound, format!("Unit {} not found", req.unit_id))
+    })?;
+    let Some(fire) = unit.fire_support else {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            format!("Unit {} cannot fire missions", req.unit_id),
+        ));
+    };
+    if unit.reload_secs > 0.0 {
+        return Err(Rejection::new(
+            CommandRejectionReason::UnitReloading,
+            format!("Unit {} is reloading for another {:.0} s", req.unit_id, unit.reload_secs.ceil()),
+        ));
+    }
+
+    let target = Position {
+        lat: req.target_lat,
+        lon: req.target_lon,
+    };
+    let range_m = haversine_distance(unit.lat, unit.lon, target.lat, target.lon) * 1000.0;
+    if range_m < fire.min_range as f64 || range_m > fire.max_range as f64 {
+        return Err(Rejection::new(
+            CommandRejectionReason::OutOfRange,
+            format!(
+                "Target is {:.0} m away; the unit fires between {:.0} and {:.0} m",
+                range_m, fire.min_range, fire.max_range
+            ),
+        ));
+    }
+    if !side_can_see(state, unit.side, &target) {
+        return Err(Rejection::new(
+            CommandRejectionReason::TargetNotSpotted,
+            "None of your units can see the target",
+        ));
+    }
+
+    let (side, damage) = (unit.side, unit.damage);
+
+    // Scatter is spread evenly over a disc around the aim point
+    let scatter_km = fire.scatter as f64 / 1000.0 * state.rng.random::<f64>().sqrt();
+    let bearing = state.rng.random::<f64>() * TAU;
+    let impact = offset_position(&target, scatter_km * bearing.cos(), scatter_km * bearing.sin());
+
+    if let Some(unit) = state.units.get_mut(&req.unit_id) {
+        unit.reload_secs = fire.reload_time as f64;
+    }
+    state.shells.push(Shell {
+        unit_id: req.unit_id.clone(),
+        impact,
+        damage,
+        blast_radius_km: fire.blast_radius as f64 / 1000.0,
+        eta_secs: fire.flight_time as f64,
+    });
+
+    outbox.send_to_side(
+        side,
+        ws_server_message::Payload::FireMissionLaunched(FireMissionLaunchedEvent {
+            session_id: state.session_id.clone(),
+            unit_id: req.unit_id.clone(),
+            target: Some(target),
+            impact_in_secs: fire.flight_time,
+        }),
+    );
+    Ok(())
+}
+
+/// Lands every shell whose flight time is up. Each one damages all units inside its
+/// blast radius, friend or foe.
+pub fn update(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    for shell in &mut state.shells {
+        shell.eta_secs -= dt;
+    }
+    let (landed, in_flight): (Vec<Shell>, Vec<Shell>) =
+        std::mem::take(&mut state.shells).into_iter().partition(|shell| shell.eta_secs <= 0.0);
+    state.shells = in_flight;
+
+    for shell in landed {
+        // Everyone sees the blast, but only those who can see the gun know who fired
+        for side in [UnitSide::Blue, UnitSide::Red] {
+            outbox.send_to_side(
+                side,
+                ws_server_message::Payload::ShellImpact(ShellImpactEvent {
+                    session_id: state.session_id.clone(),
+                    unit_id: state.id_visible_to(&shell.unit_id, side),
+                    position: Some(shell.impact),
+                    blast_radius: (shell.blast_radius_km * 1000.0) as f32,
+                }),
+            );
+        }
+
+        let hit: Vec<String> = state
+            .units_near(shell.impact.lat, shell.impact.lon, shell.blast_radius_km)
+            .map(|unit| unit.id.clone())
+            .collect();
+        let suppression = state.config.rules.suppression_per_blast;
+        for unit_id in hit {
+            if let Some(unit) = state.units.get_mut(&unit_id) {
+                morale::suppress(unit, suppression);
+            }
+            apply_damage(state, &unit_id, &shell.unit_id, shell.damage, outbox);
+        }
+    }
+}
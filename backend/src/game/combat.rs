@@ -0,0 +1,120 @@
+use rand::Rng;
+
+use crate::game::{command, morale};
+use crate::game::outbox::Outbox;
+use crate::game::state::SessionState;
+use crate::game::terrain::terrain_at;
+use crate::game::visibility::can_see;
+use crate::models::proto::{UnitDestroyedEvent, ws_server_message};
+use crate::utils::haversine_distance;
+
+/// Seconds a unit needs between two shots.
+pub const RELOAD_SECS: f64 = 3.0;
+
+struct Shot {
+    attacker_id: String,
+    target_id: String,
+    hit_chance: f64,
+    damage: u32,
+}
+
+/// Every loaded unit with an enemy in sight fires at the nearest one. Artillery waits
+/// for fire missions instead.
+pub fn resolve(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    for unit in state.units.values_mut() {
+        unit.reload_secs = (unit.reload_secs - dt).max(0.0);
+    }
+
+    let shots = pick_shots(state);
+
+    for shot in shots {
+        // The target may already have been destroyed earlier in this tick
+        if !state.units.contains_key(&shot.target_id) {
+            continue;
+        }
+        if let Some(attacker) = state.units.get_mut(&shot.attacker_id) {
+            attacker.reload_secs = RELOAD_SECS;
+        }
+        let suppression = state.config.rules.suppression_per_shot;
+        if let Some(target) = state.units.get_mut(&shot.target_id) {
+            morale::suppress(target, suppression);
+        }
+
+        if !state.rng.random_bool(shot.hit_chance) {
+            continue;
+        }
+
+        apply_damage(state, &shot.target_id, &shot.attacker_id, shot.damage, outbox);
+    }
+}
+
+/// Takes `damage` off a unit and reports it, removing the unit once its health runs out.
+pub fn apply_damage(state: &mut SessionState, target_id: &str, attacker_id: &str, damage: u32, outbox: &mut Outbox) {
+    let morale_per_damage = state.config.rules.morale_per_damage;
+    let Some(target) = state.units.get_mut(target_id) else {
+        return;
+    };
+    target.health = target.health.saturating_sub(damage);
+    morale::damaged(target, damage, morale_per_damage);
+    let (owner, spotted, health) = (target.side, target.spotted, target.health);
+
+    if health > 0 {
+        outbox.unit_delta(target_id).health = Some(health);
+        return;
+    }
+
+    // The enemy only hears about units it can see, and nobody learns who fired unless
+    // they can see the attacker
+    for side in [owner, owner.opponent()] {
+        if side != owner && !spotted {
+            continue;
+        }
+        outbox.send_to_side(
+            side,
+            ws_server_message::Payload::UnitDestroyed(UnitDestroyedEvent {
+                session_id: state.session_id.clone(),
+                unit_id: target_id.to_string(),
+                attacker_id: state.id_visible_to(attacker_id, side),
+            }),
+        );
+    }
+
+    if let Some(destroyed) = state.units.remove(target_id) {
+        command::unit_destroyed(state, &destroyed, outbox);
+        morale::unit_destroyed(state, &destroyed);
+    }
+}
+
+fn pick_shots(state: &SessionState) -> Vec<Shot> {
+    state
+        .units
+        .values()
+        // Guns with fire support only fire when ordered to, on their own reload time
+        .filter(|attacker| attacker.reload_secs <= 0.0 && attacker.fire_support.is_none())
+        .filter_map(|attacker| {
+            let (target, _) = state
+                .units_near(attacker.lat, attacker.lon, attacker.sight_range_km)
+                .filter(|target| target.side != attacker.side && can_see(state, attacker, target))
+                .map(|target| {
+                    let distance_km = haversine_distance(attacker.lat, attacker.lon, target.lat, target.lon);
+                    (target, distance_km)
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+            let attacker_terrain = terrain_at(state, attacker, attacker.lat, attacker.lon);
+            let target_terrain = terrain_at(state, target, target.lat, target.lon);
+            let hit_chance = attacker.accuracy
+                * attacker_terrain.accuracy_modifier
+                * command::effect(state, attacker).accuracy_modifier
+                * morale::suppression_factor(state, attacker)
+                * (1.0 - target_terrain.enemy_miss_chance);
+
+            Some(Shot {
+                attacker_id: attacker.id.clone(),
+                target_id: target.id.clone(),
+                hit_chance: hit_chance.clamp(0.0, 1.0),
+                damage: attacker.damage,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,82 @@
+use tracing::info;
+
+use crate::game::outbox::Outbox;
+use crate::game::state::{SessionState, UnitState};
+use crate::models::proto::{HeadquartersLostEvent, ws_server_message};
+use crate::utils::haversine_distance;
+
+/// How a side's headquarters affect one of its units.
+#[derive(Clone, Copy, Debug)]
+pub struct CommandEffect {
+    pub accuracy_modifier: f64,
+    pub speed_modifier: f64,
+    /// Seconds before the unit reacts to a new order.
+    pub order_delay_secs: f64,
+}
+
+impl Default for CommandEffect {
+    /// Sides that never had a headquarters are unaffected.
+    fn default() -> Self {
+        Self {
+            accuracy_modifier: 1.0,
+            speed_modifier: 1.0,
+            order_delay_secs: 0.0,
+        }
+    }
+}
+
+/// Units inside the radius of a friendly headquarters get its bonuses; units outside
+/// every radius react to orders late. Once a side has lost its last headquarters, all
+/// its units take the loss penalty and the order delay.
+pub fn effect(state: &SessionState, unit: &UnitState) -> CommandEffect {
+    if let Some(lost) = state.command_lost[unit.side as usize] {
+        return CommandEffect {
+            accuracy_modifier: lost.loss_accuracy_modifier as f64,
+            speed_modifier: lost.loss_speed_modifier as f64,
+            order_delay_secs: lost.order_delay as f64,
+        };
+    }
+
+    let mut delay = None;
+    let headquarters = state
+        .unit_index
+        .headquarters
+        .iter()
+        .filter_map(|hq_id| state.units.get(hq_id))
+        .filter(|hq| hq.side == unit.side);
+
+    for hq in headquarters {
+        let Some(command) = hq.command else { continue };
+        if haversine_distance(hq.lat, hq.lon, unit.lat, unit.lon) <= command.radius as f64 / 1000.0 {
+            return CommandEffect {
+                accuracy_modifier: command.accuracy_modifier as f64,
+                speed_modifier: command.speed_modifier as f64,
+                order_delay_secs: 0.0,
+            };
+        }
+        delay = Some(command.order_delay as f64);
+    }
+
+    CommandEffect {
+        order_delay_secs: delay.unwrap_or_default(),
+        ..CommandEffect::default()
+    }
+}
+
+/// Applies the side-wide penalty when `destroyed` was its side's last headquarters.
+pub fn unit_destroyed(state: &mut SessionState, destroyed: &UnitState, outbox: &mut Outbox) {
+    let Some(command) = destroyed.command else {
+        return;
+    };
+    let side = destroyed.side;
+    if state.units.values().any(|unit| unit.side == side && unit.command.is_some()) {
+        return;
+    }
+
+    info!("📡 Side {:?} lost its last headquarters in session {}", side, state.session_id);
+    state.command_lost[side as usize] = Some(command);
+    outbox.broadcast(ws_server_message::Payload::HeadquartersLost(HeadquartersLostEvent {
+        session_id: state.session_id.clone(),
+        side: side as i32,
+    }));
+}
@@ -0,0 +1,192 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::models::proto::{Unit, UnitTypeKey};
+use crate::{RawArea, load_config_from_file, load_configs_from_file};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RawUnitType {
+    #[serde(rename = "type")]
+    pub type_key: String,
+    /// Broad classes such as "infantry" or "vehicle" that area rules can refer to.
+    #[serde(default)]
+    pub categories: Vec<String>,
+    pub icon: String,
+    pub health: u32,
+    pub accuracy: f32,
+    /// Metres; also used as the engagement range.
+    pub sight_range: f32,
+    pub movement_speed: f32,
+    pub damage: u32,
+    /// Set for units that can fire missions at map positions, such as artillery.
+    #[serde(default)]
+    pub fire_support: Option<RawFireSupport>,
+    /// Names of the area types this unit can construct.
+    #[serde(default)]
+    pub builds: Vec<String>,
+    /// Set for headquarters, whose presence steadies the units around them.
+    #[serde(default)]
+    pub command: Option<RawCommandRadius>,
+    /// Supplies needed to produce the unit; units without a cost cannot be produced.
+    #[serde(default)]
+    pub cost: Option<u32>,
+    /// Seconds from ordering the unit to its arrival.
+    #[serde(default)]
+    pub build_time: f32,
+}
+
+/// Indirect fire profile of a unit type. Distances are metres, times are seconds.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct RawFireSupport {
+    pub min_range: f32,
+    pub max_range: f32,
+    pub flight_time: f32,
+    pub scatter: f32,
+    pub blast_radius: f32,
+    pub reload_time: f32,
+}
+
+impl Default for RawUnitType {
+    /// Stats for scenario units whose type is missing from `units-config.json`.
+    fn default() -> Self {
+        Self {
+            type_key: UnitTypeKey::UnitTypeUnspecified.as_str_name().to_string(),
+            categories: Vec::new(),
+            icon: String::new(),
+            health: 100,
+            accuracy: 0.5,
+            sight_range: 100.0,
+            movement_speed: 1.0,
+            damage: 10,
+            fire_support: None,
+            builds: Vec::new(),
+            command: None,
+            cost: None,
+            build_time: 0.0,
+        }
+    }
+}
+
+/// Effects of a headquarters on its side. The radius is metres, the delay seconds.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct RawCommandRadius {
+    pub radius: f32,
+    pub accuracy_modifier: f32,
+    pub speed_modifier: f32,
+    pub order_delay: f32,
+    pub loss_accuracy_modifier: f32,
+    pub loss_speed_modifier: f32,
+}
+
+/// Session-wide rules from `game-rules.json`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct GameRules {
+    /// Metres around an objective's position that count as its capture zone.
+    pub capture_radius: f32,
+    /// Seconds a side must hold a capture zone uncontested to capture it.
+    pub capture_time: f32,
+    /// Metres from an engineer that every point of a construction outline must lie within.
+    pub build_range: f32,
+    /// Supplies each side starts the game with.
+    pub starting_supplies: f32,
+    /// Supplies per second for every objective a side holds.
+    pub supplies_per_objective: f32,
+    /// Seconds between resource updates to the players.
+    pub resources_interval: f32,
+    /// Suppression a unit takes from every shot fired at it, hit or miss.
+    pub suppression_per_shot: f32,
+    /// Suppression from a shell landing with the unit inside its blast radius.
+    pub suppression_per_blast: f32,
+    /// Suppression shed per second.
+    pub suppression_recovery: f32,
+    /// Share of accuracy and speed lost at full suppression.
+    pub suppression_penalty: f32,
+    /// Morale lost for losing the whole of the unit's health; smaller hits scale down.
+    pub morale_per_damage: f32,
+    /// Metres within which a friendly unit's destruction shakes a unit.
+    pub morale_loss_radius: f32,
+    /// Morale lost for each friendly unit destroyed within `morale_loss_radius`.
+    pub morale_per_nearby_loss: f32,
+    /// Morale regained per second while not suppressed.
+    pub morale_recovery: f32,
+    /// Morale at which a routing unit rallies and takes orders again.
+    pub rally_morale: f32,
+    /// Seconds a disconnected player has to come back before they lose the game.
+    pub reconnect_grace: f32,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            capture_radius: 200.0,
+            capture_time: 10.0,
+            build_range: 150.0,
+            starting_supplies: 300.0,
+            supplies_per_objective: 2.0,
+            resources_interval: 1.0,
+            suppression_per_shot: 0.1,
+            suppression_per_blast: 0.4,
+            suppression_recovery: 0.15,
+            suppression_penalty: 0.5,
+            morale_per_damage: 0.5,
+            morale_loss_radius: 300.0,
+            morale_per_nearby_loss: 0.25,
+            morale_recovery: 0.02,
+            rally_morale: 0.5,
+            reconnect_grace: 60.0,
+        }
+    }
+}
+
+/// Unit, area and rule definitions shared with the frontend through `shared/configs`.
+#[derive(Clone, Debug, Default)]
+pub struct GameConfig {
+    pub unit_types: Vec<RawUnitType>,
+    pub area_types: Vec<RawArea>,
+    pub rules: GameRules,
+}
+
+impl GameConfig {
+    pub fn load() -> Result<Self, String> {
+        Ok(Self {
+            unit_types: load_configs_from_file(Path::new("../shared/configs/units-config.json"))?,
+            area_types: load_configs_from_file(Path::new("../shared/configs/areas-config.json"))?,
+            rules: load_config_from_file(Path::new("../shared/configs/game-rules.json"))?,
+        })
+    }
+
+    /// Resolves a scenario unit to its type, by icon first and then by `unit_key`,
+    /// which the editor stores either as the enum name or its numeric value.
+    pub fn unit_type(&self, unit: &Unit) -> Option<&RawUnitType> {
+        let by_icon = self
+            .unit_types
+            .iter()
+            .find(|t| t.icon.eq_ignore_ascii_case(&unit.icon));
+
+        by_icon.or_else(|| {
+            let key_name = match unit.unit_key.parse::<i32>() {
+                Ok(value) => UnitTypeKey::try_from(value).ok()?.as_str_name().to_string(),
+                Err(_) => unit.unit_key.to_ascii_uppercase(),
+            };
+            self.unit_types
+                .iter()
+                .find(|t| t.type_key.eq_ignore_ascii_case(&key_name))
+        })
+    }
+
+    /// Looks a unit type up by its type key, e.g. "INFANTRY".
+    pub fn unit_type_by_key(&self, type_key: &str) -> Option<&RawUnitType> {
+        self.unit_types
+            .iter()
+            .find(|t| t.type_key.eq_ignore_ascii_case(type_key))
+    }
+
+    /// Scenario areas store the lowercased area name as their `type`.
+    pub fn area_type(&self, name: &str) -> Option<&RawArea> {
+        self.area_types
+            .iter()
+            .find(|a| a.name.eq_ignore_ascii_case(name))
+    }
+}
@@ -0,0 +1,55 @@
+use tracing::info;
+
+use crate::game::outbox::Outbox;
+use crate::game::state::SessionState;
+use crate::game::victory::{self, GameOutcome};
+use crate::models::proto::{PlayerDisconnectedEvent, PlayerReconnectedEvent, ws_server_message};
+
+/// Starts the reconnect countdown for a player whose socket closed.
+pub fn disconnected(state: &mut SessionState, user_id: &str, outbox: &mut Outbox) {
+    if state.side_of(user_id).is_none() || state.away.contains_key(user_id) {
+        return;
+    }
+
+    let grace_secs = state.config.rules.reconnect_grace as f64;
+    info!(
+        "📴 {} dropped out of session {}; waiting {:.0} s for them",
+        user_id, state.session_id, grace_secs
+    );
+    state.away.insert(user_id.to_string(), grace_secs);
+    outbox.broadcast(ws_server_message::Payload::PlayerDisconnected(PlayerDisconnectedEvent {
+        session_id: state.session_id.clone(),
+        user_id: user_id.to_string(),
+        reconnect_within_secs: grace_secs as f32,
+    }));
+}
+
+/// Brings a returning player back into the game with a snapshot of their side.
+pub fn reconnected(state: &mut SessionState, user_id: &str, outbox: &mut Outbox) {
+    let Some(side) = state.side_of(user_id) else {
+        return;
+    };
+
+    if state.away.remove(user_id).is_some() {
+        info!("📶 {} is back in session {}", user_id, state.session_id);
+        outbox.broadcast(ws_server_message::Payload::PlayerReconnected(PlayerReconnectedEvent {
+            session_id: state.session_id.clone(),
+            user_id: user_id.to_string(),
+        }));
+    }
+    outbox.send_to_user(user_id, ws_server_message::Payload::GameSnapshot(state.snapshot(side)));
+}
+
+/// Runs the reconnect countdowns. A player who runs out of time loses.
+pub fn update(state: &mut SessionState, dt: f64) -> Option<GameOutcome> {
+    for remaining in state.away.values_mut() {
+        *remaining -= dt;
+    }
+
+    let expired = state
+        .away
+        .iter()
+        .find(|(_, remaining)| **remaining <= 0.0)
+        .map(|(user_id, _)| user_id.clone())?;
+    state.side_of(&expired).map(victory::abandoned)
+}
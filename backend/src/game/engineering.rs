@@ -0,0 +1,152 @@
+use tracing::info;
+use uuid::Uuid;
+
+use crate::game::Rejection;
+use crate::game::movement::cancelled;
+use crate::game::outbox::Outbox;
+use crate::game::spatial::AreaIndex;
+use crate::game::state::SessionState;
+use crate::models::proto::{
+    AreaCreatedEvent, BuildRequest, CommandRejectionReason, ConstructionAbandonedEvent,
+    ConstructionStartedEvent, OrderCancelReason, ScenarioArea, UnitSide, ws_server_message,
+};
+use crate::utils::haversine_distance;
+
+/// An area an engineer is still working on.
+#[derive(Clone, Debug)]
+pub struct Construction {
+    pub unit_id: String,
+    pub side: UnitSide,
+    /// Area type name as written in `areas-config.json`.
+    pub area_type: String,
+    pub area: ScenarioArea,
+    pub remaining_secs: f64,
+}
+
+/// Starts building an area on the given outline. The engineer halts and has to stay
+/// put until the build time is up.
+pub fn build(state: &mut SessionState, req: &BuildRequest, outbox: &mut Outbox) -> Result<(), Rejection> {
+    let unit = state.units.get(&req.unit_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::UnitNotFound, format!("Unit {} not found", req.unit_id))
+    })?;
+
+    let buildable = state.config.area_type(&req.area_type).and_then(|area_type| {
+        let build_time = area_type.build_time?;
+        let allowed = unit.builds.iter().any(|name| name.eq_ignore_ascii_case(&area_type.name));
+        allowed.then_some((area_type, build_time))
+    });
+    let Some((area_type, build_time)) = buildable else {
+        return Err(Rejection::new(
+            CommandRejectionReason::CannotBuild,
+            format!("Unit {} cannot build a {}", req.unit_id, req.area_type),
+        ));
+    };
+
+    if state.constructions.iter().any(|c| c.unit_id == req.unit_id) {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            format!("Unit {} is already building", req.unit_id),
+        ));
+    }
+
+    let points = req.outline.as_ref().map(|ring| ring.points.as_slice()).unwrap_or_default();
+    if points.len() < 3 {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            "An outline needs at least three points",
+        ));
+    }
+    let range_km = state.config.rules.build_range as f64 / 1000.0;
+    if points
+        .iter()
+        .any(|p| haversine_distance(unit.lat, unit.lon, p.lat, p.lon) > range_km)
+    {
+        return Err(Rejection::new(
+            CommandRejectionReason::OutOfRange,
+            format!("Every point of the outline must be within {:.0} m of the unit", range_km * 1000.0),
+        ));
+    }
+
+    let construction = Construction {
+        unit_id: req.unit_id.clone(),
+        side: unit.side,
+        area_type: area_type.name.clone(),
+        area: ScenarioArea {
+            id: Some(Uuid::new_v4().to_string()),
+            r#type: area_type.name.to_lowercase(),
+            coordinates: vec![req.outline.clone().unwrap_or_default()],
+        },
+        remaining_secs: build_time as f64,
+    };
+
+    let session_id = state.session_id.clone();
+    if let Some(unit) = state.units.get_mut(&req.unit_id)
+        && unit.order.take().is_some()
+    {
+        cancelled(&session_id, unit, OrderCancelReason::Superseded, outbox);
+    }
+
+    outbox.send_to_side(
+        construction.side,
+        ws_server_message::Payload::ConstructionStarted(ConstructionStartedEvent {
+            session_id,
+            unit_id: construction.unit_id.clone(),
+            area_type: construction.area_type.clone(),
+            complete_in_secs: build_time,
+        }),
+    );
+    state.constructions.push(construction);
+    Ok(())
+}
+
+/// Progresses every construction. One whose engineer was destroyed or moved off is
+/// abandoned; a finished one becomes part of the map.
+pub fn update(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    let mut created = false;
+
+    for mut construction in std::mem::take(&mut state.constructions) {
+        let working = state
+            .units
+            .get(&construction.unit_id)
+            .is_some_and(|unit| unit.order.is_none());
+
+        if !working {
+            outbox.send_to_side(
+                construction.side,
+                ws_server_message::Payload::ConstructionAbandoned(ConstructionAbandonedEvent {
+                    session_id: state.session_id.clone(),
+                    unit_id: construction.unit_id,
+                    area_type: construction.area_type,
+                }),
+            );
+            continue;
+        }
+
+        construction.remaining_secs -= dt;
+        if construction.remaining_secs > 0.0 {
+            state.constructions.push(construction);
+            continue;
+        }
+
+        info!(
+            "🏗️ Unit {} finished a {} in session {}",
+            construction.unit_id, construction.area_type, state.session_id
+        );
+        for side in [UnitSide::Blue, UnitSide::Red] {
+            outbox.send_to_side(
+                side,
+                ws_server_message::Payload::AreaCreated(AreaCreatedEvent {
+                    session_id: state.session_id.clone(),
+                    area: Some(construction.area.clone()),
+                    built_by: state.id_visible_to(&construction.unit_id, side),
+                }),
+            );
+        }
+        state.areas.push(construction.area);
+        created = true;
+    }
+
+    if created {
+        state.area_index = AreaIndex::build(&state.areas);
+    }
+}
@@ -0,0 +1,183 @@
+use tracing::info;
+
+use crate::game::movement::{cancelled, path_updated};
+use crate::game::outbox::Outbox;
+use crate::game::state::{MoveOrder, SessionState, UnitState};
+use crate::game::terrain::weighted_areas;
+use crate::models::proto::{OrderCancelReason, Position};
+use crate::pathfinding::find_path;
+use crate::utils::haversine_distance;
+
+/// Smallest change in morale or suppression worth telling the players about.
+const REPORT_STEP: f32 = 0.05;
+
+/// Share of its accuracy and speed a unit keeps under its current suppression.
+pub fn suppression_factor(state: &SessionState, unit: &UnitState) -> f64 {
+    1.0 - unit.suppression * state.config.rules.suppression_penalty as f64
+}
+
+pub fn suppress(unit: &mut UnitState, amount: f32) {
+    unit.suppression = (unit.suppression + amount as f64).min(1.0);
+}
+
+/// A hit shakes the unit in proportion to the share of its health it took.
+pub fn damaged(unit: &mut UnitState, damage: u32, morale_per_damage: f32) {
+    let share = damage as f64 / unit.max_health.max(1) as f64;
+    unit.morale = (unit.morale - share * morale_per_damage as f64).max(0.0);
+}
+
+/// Every friendly unit near `destroyed` loses morale.
+pub fn unit_destroyed(state: &mut SessionState, destroyed: &UnitState) {
+    let rules = &state.config.rules;
+    let radius_km = rules.morale_loss_radius as f64 / 1000.0;
+    let loss = rules.morale_per_nearby_loss as f64;
+
+    let shaken: Vec<String> = state
+        .units_near(destroyed.lat, destroyed.lon, radius_km)
+        .filter(|unit| unit.side == destroyed.side)
+        .map(|unit| unit.id.clone())
+        .collect();
+    for unit_id in shaken {
+        if let Some(unit) = state.units.get_mut(&unit_id) {
+            unit.morale = (unit.morale - loss).max(0.0);
+        }
+    }
+}
+
+/// Route to the closest other friendly headquarters, around impassable terrain, for a
+/// routing unit to fall back along.
+fn retreat_route(state: &SessionState, unit: &UnitState) -> Option<Vec<Position>> {
+    let start = Position {
+        lat: unit.lat,
+        lon: unit.lon,
+    };
+    let headquarters = nearest_headquarters(state, unit)?;
+    find_path(&start, &headquarters, &weighted_areas(state, unit), &state.area_index)
+}
+
+/// Position of the closest other friendly headquarters.
+fn nearest_headquarters(state: &SessionState, unit: &UnitState) -> Option<Position> {
+    state
+        .unit_index
+        .headquarters
+        .iter()
+        .filter_map(|hq_id| state.units.get(hq_id))
+        .filter(|hq| hq.side == unit.side && hq.id != unit.id)
+        .min_by(|a, b| {
+            let da = haversine_distance(unit.lat, unit.lon, a.lat, a.lon);
+            let db = haversine_distance(unit.lat, unit.lon, b.lat, b.lon);
+            da.total_cmp(&db)
+        })
+        .map(|hq| Position { lat: hq.lat, lon: hq.lon })
+}
+
+/// Recovers suppression and morale, routs units whose morale broke and rallies those
+/// that recovered, then reports noticeable changes to whoever can see each unit.
+pub fn update(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    let rules = state.config.rules.clone();
+
+    // Before recovery, which would lift a unit just knocked to zero back above it
+    let broken: Vec<(String, Option<Vec<Position>>)> = state
+        .units
+        .values()
+        .filter(|unit| !unit.routing && unit.morale <= 0.0)
+        .map(|unit| (unit.id.clone(), retreat_route(state, unit)))
+        .collect();
+
+    for unit in state.units.values_mut() {
+        unit.suppression = (unit.suppression - rules.suppression_recovery as f64 * dt).max(0.0);
+        let recovery = rules.morale_recovery as f64 * (1.0 - unit.suppression) * dt;
+        unit.morale = (unit.morale + recovery).min(1.0);
+    }
+
+    for (unit_id, retreat) in broken {
+        let Some(unit) = state.units.get_mut(&unit_id) else {
+            continue;
+        };
+        info!("😱 Unit {} routed in session {}", unit_id, state.session_id);
+        unit.routing = true;
+        if unit.order.take().is_some() {
+            cancelled(&state.session_id, unit, OrderCancelReason::Routed, outbox);
+        }
+        if let Some(retreat) = retreat {
+            unit.order = Some(MoveOrder {
+                waypoints: retreat.into(),
+                delay_secs: 0.0,
+            });
+            path_updated(&state.session_id, unit, outbox);
+        }
+    }
+
+    for unit in state.units.values_mut() {
+        if unit.routing && unit.morale >= rules.rally_morale as f64 {
+            info!("🚩 Unit {} rallied in session {}", unit.id, state.session_id);
+            unit.routing = false;
+            if unit.order.take().is_some() {
+                path_updated(&state.session_id, unit, outbox);
+            }
+        }
+
+        let (morale, suppression, routing) = (unit.morale as f32, unit.suppression as f32, unit.routing);
+        let reported = &mut unit.reported_status;
+        if (morale - reported.0).abs() >= REPORT_STEP {
+            reported.0 = morale;
+            outbox.unit_delta(&unit.id).morale = Some(morale);
+        }
+        if (suppression - reported.1).abs() >= REPORT_STEP {
+            reported.1 = suppression;
+            outbox.unit_delta(&unit.id).suppression = Some(suppression);
+        }
+        if routing != reported.2 {
+            reported.2 = routing;
+            outbox.unit_delta(&unit.id).routing = Some(routing);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::GameConfig;
+    use crate::models::proto::{Scenario, Unit, UnitSide};
+
+    fn session() -> SessionState {
+        let scenario = Scenario {
+            units: vec![Unit {
+                id: None,
+                position: Some(Position { lat: 50.0, lon: 14.0 }),
+                unit_key: "INFANTRY".to_string(),
+                side: UnitSide::Blue as i32,
+                icon: String::new(),
+            }],
+            ..Default::default()
+        };
+        SessionState::from_scenario("test".to_string(), "p1".to_string(), None, scenario, GameConfig::default())
+    }
+
+    #[test]
+    fn a_unit_at_zero_morale_routs() {
+        let mut state = session();
+        let unit = state.units.values_mut().next().unwrap();
+        unit.morale = 0.0;
+        unit.suppression = 0.3;
+
+        update(&mut state, 0.1, &mut Outbox::default());
+
+        let unit = state.units.values().next().unwrap();
+        assert!(unit.routing);
+        assert!(unit.morale > 0.0);
+    }
+
+    #[test]
+    fn a_routing_unit_rallies_once_morale_recovers() {
+        let mut state = session();
+        let rally_morale = state.config.rules.rally_morale as f64;
+        let unit = state.units.values_mut().next().unwrap();
+        unit.routing = true;
+        unit.morale = rally_morale;
+
+        update(&mut state, 0.1, &mut Outbox::default());
+
+        assert!(!state.units.values().next().unwrap().routing);
+    }
+}
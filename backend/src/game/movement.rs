@@ -0,0 +1,392 @@
+use crate::game::{command, morale};
+use crate::game::outbox::Outbox;
+use crate::game::state::{MoveOrder, ReportedMotion, SessionState, UnitState};
+use crate::game::terrain::{blocked_along, is_blocked, terrain_at, weighted_areas};
+use crate::game::Rejection;
+use crate::models::proto::{
+    CommandRejectionReason, MoveUnitPathRequest, MoveUnitRequest,
+    OrderCancelReason, Position, StopUnitRequest, UnitMovementStarted, UnitOrderCancelledEvent, UnitPathUpdatedEvent,
+    ws_server_message,
+};
+use crate::pathfinding::find_path;
+use crate::utils::{haversine_distance, interpolate};
+
+/// How far short of closed ground a unit halts, in km.
+const EDGE_MARGIN_KM: f64 = 0.001;
+/// Relative change in speed before clients are sent a correction.
+const SPEED_TOLERANCE: f64 = 0.05;
+/// Distance in km between a unit and where clients believe it is before they are sent
+/// a correction.
+const POSITION_TOLERANCE_KM: f64 = 0.05;
+
+fn unit<'a>(state: &'a SessionState, unit_id: &str) -> Result<&'a UnitState, Rejection> {
+    state.units.get(unit_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::UnitNotFound, format!("Unit {} not found", unit_id))
+    })
+}
+
+fn unit_mut<'a>(state: &'a mut SessionState, unit_id: &str) -> Result<&'a mut UnitState, Rejection> {
+    state.units.get_mut(unit_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::UnitNotFound, format!("Unit {} not found", unit_id))
+    })
+}
+
+/// Replaces the unit's current order; the unit continues from wherever it is now.
+/// A fastest-route order is planned around slow and impassable terrain before the
+/// unit sets off.
+pub fn issue_order(state: &mut SessionState, req: &MoveUnitRequest, outbox: &mut Outbox) -> Result<(), Rejection> {
+    let target = Position {
+        lat: req.target_lat,
+        lon: req.target_lon,
+    };
+
+    let waypoints = if req.fastest_route {
+        let unit = unit(state, &req.unit_id)?;
+        let start = Position {
+            lat: unit.lat,
+            lon: unit.lon,
+        };
+        check_waypoints(state, unit, &[target])?;
+        find_path(&start, &target, &weighted_areas(state, unit), &state.area_index).ok_or_else(|| {
+            Rejection::new(CommandRejectionReason::NoRoute, "No passable route to the target")
+        })?
+    } else {
+        vec![target]
+    };
+
+    set_path(state, &req.unit_id, waypoints, false, outbox)
+}
+
+pub fn issue_path(state: &mut SessionState, req: &MoveUnitPathRequest, outbox: &mut Outbox) -> Result<(), Rejection> {
+    if req.waypoints.is_empty() {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            "A path needs at least one waypoint",
+        ));
+    }
+    set_path(state, &req.unit_id, req.waypoints.clone(), req.append, outbox)
+}
+
+/// Refuses coordinates that are not on the map, and orders that end a leg inside terrain
+/// the unit cannot enter.
+fn check_waypoints(state: &SessionState, unit: &UnitState, waypoints: &[Position]) -> Result<(), Rejection> {
+    if !waypoints.iter().all(on_map) {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            "Waypoints must be valid coordinates",
+        ));
+    }
+
+    match waypoints
+        .iter()
+        .find(|waypoint| is_blocked(state, unit, waypoint.lat, waypoint.lon))
+    {
+        Some(_) => Err(Rejection::new(
+            CommandRejectionReason::TerrainImpassable,
+            "The destination is impassable for this unit",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Refuses orders whose straight legs run through terrain the unit cannot enter.
+fn check_legs(state: &SessionState, unit: &UnitState, from: &Position, waypoints: &[Position]) -> Result<(), Rejection> {
+    let crosses = std::iter::once(from)
+        .chain(waypoints)
+        .zip(waypoints)
+        .any(|(a, b)| blocked_along(state, unit, a, b).is_some());
+    if crosses {
+        return Err(Rejection::new(
+            CommandRejectionReason::TerrainImpassable,
+            "The route crosses terrain impassable for this unit",
+        ));
+    }
+    Ok(())
+}
+
+fn on_map(position: &Position) -> bool {
+    (-90.0..=90.0).contains(&position.lat) && (-180.0..=180.0).contains(&position.lon)
+}
+
+/// Appends to or replaces the unit's waypoints. Replacing a path in progress cancels it.
+fn set_path(
+    state: &mut SessionState,
+    unit_id: &str,
+    waypoints: Vec<Position>,
+    append: bool,
+    outbox: &mut Outbox,
+) -> Result<(), Rejection> {
+    let unit = unit(state, unit_id)?;
+    check_waypoints(state, unit, &waypoints)?;
+    let from = match &unit.order {
+        Some(order) if append => order.waypoints.back().copied(),
+        _ => None,
+    };
+    let from = from.unwrap_or(Position {
+        lat: unit.lat,
+        lon: unit.lon,
+    });
+    check_legs(state, unit, &from, &waypoints)?;
+    let delay_secs = command::effect(state, unit).order_delay_secs;
+
+    let session_id = state.session_id.clone();
+    let unit = unit_mut(state, unit_id)?;
+
+    match unit.order.as_mut() {
+        Some(order) if append => order.waypoints.extend(waypoints),
+        _ => {
+            let previous = unit.order.replace(MoveOrder {
+                waypoints: waypoints.into(),
+                delay_secs,
+            });
+            if previous.is_some() {
+                cancelled(&session_id, unit, OrderCancelReason::Superseded, outbox);
+            }
+        }
+    }
+
+    path_updated(&session_id, unit, outbox);
+    Ok(())
+}
+
+/// Halts the unit where it stands.
+pub fn stop(state: &mut SessionState, req: &StopUnitRequest, outbox: &mut Outbox) -> Result<(), Rejection> {
+    let session_id = state.session_id.clone();
+    let unit = unit_mut(state, &req.unit_id)?;
+
+    if unit.order.take().is_some() {
+        cancelled(&session_id, unit, OrderCancelReason::Stopped, outbox);
+    }
+    Ok(())
+}
+
+pub fn cancelled(session_id: &str, unit: &UnitState, reason: OrderCancelReason, outbox: &mut Outbox) {
+    outbox.send_to_side(
+        unit.side,
+        ws_server_message::Payload::UnitOrderCancelled(UnitOrderCancelledEvent {
+            session_id: session_id.to_string(),
+            unit_id: unit.id.clone(),
+            reason: reason as i32,
+            position: Some(Position {
+                lat: unit.lat,
+                lon: unit.lon,
+            }),
+        }),
+    );
+}
+
+pub fn path_updated(session_id: &str, unit: &UnitState, outbox: &mut Outbox) {
+    let (remaining, starts_in_secs) = unit
+        .order
+        .as_ref()
+        .map(|order| (order.waypoints.iter().cloned().collect(), order.delay_secs as f32))
+        .unwrap_or_default();
+
+    outbox.send_to_side(
+        unit.side,
+        ws_server_message::Payload::UnitPathUpdated(UnitPathUpdatedEvent {
+            session_id: session_id.to_string(),
+            unit_id: unit.id.clone(),
+            remaining,
+            starts_in_secs,
+        }),
+    );
+}
+
+/// Where a unit ends up after one step along its waypoints.
+struct Step {
+    unit_id: String,
+    lat: f64,
+    lon: f64,
+    waypoints_reached: usize,
+    blocked: bool,
+}
+
+/// Kilometres per second the unit covers right now: its own speed scaled by the terrain
+/// under it, by its headquarters and by suppression.
+fn speed(state: &SessionState, unit: &UnitState) -> f64 {
+    let terrain = terrain_at(state, unit, unit.lat, unit.lon);
+    let speed = unit.movement_speed
+        * terrain.movement_speed_modifier
+        * command::effect(state, unit).speed_modifier
+        * morale::suppression_factor(state, unit);
+    speed.max(0.01)
+}
+
+/// Walks `dt` seconds along the unit's waypoints, carrying leftover distance past each
+/// reached waypoint and stopping short of closed ground. Speed is taken at the start
+/// of the step.
+fn plan_step(state: &SessionState, unit: &UnitState, dt: f64) -> Option<Step> {
+    let order = unit.order.as_ref().filter(|order| order.delay_secs <= 0.0)?;
+    let mut budget_km = speed(state, unit) * dt;
+    let mut from = Position {
+        lat: unit.lat,
+        lon: unit.lon,
+    };
+    let mut waypoints_reached = 0;
+
+    for next in &order.waypoints {
+        let remaining_km = haversine_distance(from.lat, from.lon, next.lat, next.lon);
+        let t = if remaining_km > budget_km { budget_km / remaining_km } else { 1.0 };
+        let to = Position {
+            lat: interpolate(from.lat, next.lat, t),
+            lon: interpolate(from.lon, next.lon, t),
+        };
+
+        // Halt just short of the first edge of closed ground along the way
+        if let Some(cut) = blocked_along(state, unit, &from, &to) {
+            let leg_km = (remaining_km * t).max(EDGE_MARGIN_KM);
+            let edge = (cut - EDGE_MARGIN_KM / leg_km).max(0.0);
+            return Some(Step {
+                unit_id: unit.id.clone(),
+                lat: interpolate(from.lat, to.lat, edge),
+                lon: interpolate(from.lon, to.lon, edge),
+                waypoints_reached,
+                blocked: true,
+            });
+        }
+
+        from = to;
+        if t < 1.0 {
+            break;
+        }
+        budget_km -= remaining_km;
+        waypoints_reached += 1;
+    }
+
+    Some(Step {
+        unit_id: unit.id.clone(),
+        lat: from.lat,
+        lon: from.lon,
+        waypoints_reached,
+        blocked: false,
+    })
+}
+
+/// Moves every unit with an order one step. A unit about to enter impassable terrain
+/// halts at its edge and its order is cancelled.
+pub fn advance(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    for order in state.units.values_mut().filter_map(|unit| unit.order.as_mut()) {
+        order.delay_secs = (order.delay_secs - dt).max(0.0);
+    }
+
+    let steps: Vec<Step> = state
+        .units
+        .values()
+        .filter_map(|unit| plan_step(state, unit, dt))
+        .collect();
+
+    for step in steps {
+        let Some(unit) = state.units.get_mut(&step.unit_id) else {
+            continue;
+        };
+
+        if step.blocked {
+            unit.lat = step.lat;
+            unit.lon = step.lon;
+            unit.order = None;
+            cancelled(&state.session_id, unit, OrderCancelReason::Blocked, outbox);
+            continue;
+        }
+
+        unit.lat = step.lat;
+        unit.lon = step.lon;
+        if let Some(order) = unit.order.as_mut() {
+            order.waypoints.drain(..step.waypoints_reached);
+            if order.waypoints.is_empty() {
+                unit.order = None;
+            }
+        }
+        if step.waypoints_reached > 0 {
+            path_updated(&state.session_id, unit, outbox);
+        }
+    }
+}
+
+/// The unit's movement as last reported, or a stop where it stands. The enemy only
+/// gets the next waypoint.
+pub fn movement_started(state: &SessionState, unit: &UnitState, full_path: bool) -> UnitMovementStarted {
+    let mut motion = unit.reported_motion.clone().unwrap_or_else(|| ReportedMotion {
+        start: Position {
+            lat: unit.lat,
+            lon: unit.lon,
+        },
+        started_at_secs: state.game_time_secs(),
+        ..ReportedMotion::default()
+    });
+    if !full_path {
+        motion.path.truncate(1);
+    }
+
+    let eta_secs = if motion.speed > 0.0 {
+        motion.started_at_secs + motion.length_km() / motion.speed
+    } else {
+        motion.started_at_secs
+    };
+
+    UnitMovementStarted {
+        session_id: state.session_id.clone(),
+        unit_id: unit.id.clone(),
+        start: Some(motion.start),
+        path: motion.path,
+        speed_km_per_sec: motion.speed,
+        started_at_secs: motion.started_at_secs,
+        eta_secs,
+    }
+}
+
+/// Tells the players about units that set off, stopped, reached a waypoint, changed
+/// speed or drifted from where clients believe they are. Clients move units on their
+/// own in between.
+pub fn report(state: &mut SessionState, outbox: &mut Outbox) {
+    let now = state.game_time_secs();
+    let changes: Vec<(String, Option<ReportedMotion>)> = state
+        .units
+        .values()
+        .filter_map(|unit| {
+            let motion = unit.order.as_ref().map(|order| (order, speed(state, unit)));
+            let unchanged = match (motion, &unit.reported_motion) {
+                (None, None) => true,
+                (Some((order, speed)), Some(reported)) => {
+                    let predicted = reported.position_at(now);
+                    order.waypoints.iter().eq(&reported.path)
+                        && (speed - reported.speed).abs() <= reported.speed * SPEED_TOLERANCE
+                        && haversine_distance(predicted.lat, predicted.lon, unit.lat, unit.lon)
+                            <= POSITION_TOLERANCE_KM
+                }
+                _ => false,
+            };
+            if unchanged {
+                return None;
+            }
+
+            let motion = motion.map(|(order, speed)| ReportedMotion {
+                start: Position {
+                    lat: unit.lat,
+                    lon: unit.lon,
+                },
+                path: order.waypoints.iter().cloned().collect(),
+                speed,
+                started_at_secs: now + order.delay_secs,
+            });
+            Some((unit.id.clone(), motion))
+        })
+        .collect();
+
+    for (unit_id, motion) in changes {
+        let Some(unit) = state.units.get_mut(&unit_id) else {
+            continue;
+        };
+        unit.reported_motion = motion;
+
+        let unit = &state.units[&unit_id];
+        outbox.send_to_side(
+            unit.side,
+            ws_server_message::Payload::UnitMovementStarted(movement_started(state, unit, true)),
+        );
+        outbox.send_to_spotters(
+            &unit.id,
+            ws_server_message::Payload::UnitMovementStarted(movement_started(state, unit, false)),
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::game::outbox::Outbox;
+use crate::game::state::{ObjectiveControl, SessionState};
+use crate::game::visibility::side_can_see;
+use crate::models::proto::{ObjectiveState, ObjectiveStateChangedEvent, UnitSide, ws_server_message};
+
+/// Capture progress is reported in steps of this size rather than every tick.
+const PROGRESS_STEP: f64 = 0.1;
+
+fn progress_step(progress: f64) -> u32 {
+    (progress / PROGRESS_STEP).floor() as u32
+}
+
+/// Whether `side` may follow a capture of the objective: it is the side capturing it,
+/// or one of its units can see the objective.
+pub fn capture_visible_to(state: &SessionState, control: &ObjectiveControl, side: UnitSide) -> bool {
+    control.capturing_side == Some(side)
+        || control
+            .objective
+            .position
+            .as_ref()
+            .is_some_and(|position| side_can_see(state, side, position))
+}
+
+/// Sides with at least one unit inside the objective's capture zone.
+fn sides_in_zone(state: &SessionState, control: &ObjectiveControl, radius_km: f64) -> Vec<UnitSide> {
+    let Some(position) = control.objective.position.as_ref() else {
+        return Vec::new();
+    };
+
+    [UnitSide::Blue, UnitSide::Red]
+        .into_iter()
+        .filter(|side| {
+            state
+                .units_near(position.lat, position.lon, radius_km)
+                .any(|unit| unit.side == *side)
+        })
+        .collect()
+}
+
+/// Advances capture progress. A side alone in a zone captures it after `capture_time`
+/// seconds; a contested zone freezes, and leaving it abandons the capture. Changes of
+/// owner go to everyone; a capture under way only to the sides that can see it.
+pub fn update(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    let radius_km = state.config.rules.capture_radius as f64 / 1000.0;
+    let capture_time = (state.config.rules.capture_time as f64).max(dt);
+
+    for index in 0..state.objectives.len() {
+        let present = sides_in_zone(state, &state.objectives[index], radius_km);
+        let control = &mut state.objectives[index];
+        let before = (control.state(), control.owner, control.capturing_side, progress_step(control.progress));
+
+        match present.as_slice() {
+            [side] if control.owner != Some(*side) => {
+                if control.capturing_side != Some(*side) {
+                    control.capturing_side = Some(*side);
+                    control.progress = 0.0;
+                }
+                control.progress = (control.progress + dt / capture_time).min(1.0);
+
+                if control.progress >= 1.0 {
+                    control.owner = Some(*side);
+                    control.capturing_side = None;
+                    control.objective.state = ObjectiveState::Captured as i32;
+                } else {
+                    control.objective.state = ObjectiveState::Capturing as i32;
+                }
+            }
+            [] | [_] if control.capturing_side.is_some() => {
+                control.capturing_side = None;
+                let (objective_state, progress) = control.settled();
+                control.progress = progress;
+                control.objective.state = objective_state as i32;
+            }
+            _ => {}
+        }
+
+        let after = (control.state(), control.owner, control.capturing_side, progress_step(control.progress));
+        if before == after {
+            continue;
+        }
+
+        let changed = ws_server_message::Payload::ObjectiveStateChanged(ObjectiveStateChangedEvent {
+            session_id: state.session_id.clone(),
+            objective_id: control.id().to_string(),
+            letter: control.objective.letter.clone(),
+            state: control.objective.state,
+            progress: control.progress as f32,
+            owner: control.owner.map(|side| side as i32),
+            capturing_side: control.capturing_side.map(|side| side as i32),
+        });
+        if before.1 != after.1 {
+            outbox.broadcast(changed);
+            continue;
+        }
+
+        // The side that just stopped capturing still hears about it
+        let control = &state.objectives[index];
+        for side in [UnitSide::Blue, UnitSide::Red] {
+            if before.2 == Some(side) || capture_visible_to(state, control, side) {
+                outbox.send_to_side(side, changed.clone());
+            }
+        }
+    }
+}
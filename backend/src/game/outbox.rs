@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::ws::Message;
+use prost::Message as ProstMessage;
+use tracing::error;
+
+use crate::Sockets;
+use crate::game::state::SessionState;
+use crate::models::proto::{TickUpdate, UnitDelta, UnitSide, WsServerMessage, ws_server_message};
+
+enum Recipient {
+    Session,
+    User(String),
+    Side(UnitSide),
+    /// The enemy while the unit is spotted.
+    Spotters(String),
+}
+
+/// Server messages produced during one tick, flushed together once the tick is done.
+/// Recipients are resolved at flush time, against the visibility at the end of the tick.
+#[derive(Default)]
+pub struct Outbox {
+    messages: Vec<(Recipient, ws_server_message::Payload)>,
+    /// Changed unit fields, sent to the unit's observers as one `TickUpdate`.
+    deltas: HashMap<String, UnitDelta>,
+}
+
+impl Outbox {
+    pub fn broadcast(&mut self, payload: ws_server_message::Payload) {
+        self.messages.push((Recipient::Session, payload));
+    }
+
+    pub fn send_to_user(&mut self, user_id: &str, payload: ws_server_message::Payload) {
+        self.messages
+            .push((Recipient::User(user_id.to_string()), payload));
+    }
+
+    pub fn send_to_side(&mut self, side: UnitSide, payload: ws_server_message::Payload) {
+        self.messages.push((Recipient::Side(side), payload));
+    }
+
+    pub fn send_to_spotters(&mut self, unit_id: &str, payload: ws_server_message::Payload) {
+        self.messages
+            .push((Recipient::Spotters(unit_id.to_string()), payload));
+    }
+
+    /// This tick's changes to a unit; set the fields that changed.
+    pub fn unit_delta(&mut self, unit_id: &str) -> &mut UnitDelta {
+        self.deltas
+            .entry(unit_id.to_string())
+            .or_insert_with(|| UnitDelta {
+                unit_id: unit_id.to_string(),
+                ..Default::default()
+            })
+    }
+
+    /// Sends every connected player one frame per message, starting with the tick update,
+    /// each numbered with the player's next sequence number.
+    pub async fn flush(self, state: &mut SessionState, sockets: &Sockets) {
+        if self.messages.is_empty() && self.deltas.is_empty() {
+            return;
+        }
+
+        let mut frames: Vec<(String, ws_server_message::Payload)> = Vec::new();
+        for side in [UnitSide::Blue, UnitSide::Red] {
+            let Some(user_id) = state.player_for(side) else { continue };
+            let units: Vec<UnitDelta> = self
+                .deltas
+                .values()
+                .filter(|delta| {
+                    state
+                        .units
+                        .get(&delta.unit_id)
+                        .is_some_and(|unit| state.is_visible_to(unit, side))
+                })
+                .cloned()
+                .collect();
+            if !units.is_empty() {
+                let update = TickUpdate {
+                    session_id: state.session_id.clone(),
+                    tick: state.tick,
+                    units,
+                };
+                frames.push((user_id.clone(), ws_server_message::Payload::TickUpdate(update)));
+            }
+        }
+
+        for (recipient, payload) in self.messages {
+            let users: Vec<&String> = match &recipient {
+                Recipient::Session => state.players().collect(),
+                Recipient::User(user_id) => vec![user_id],
+                Recipient::Side(side) => state.player_for(*side).into_iter().collect(),
+                Recipient::Spotters(unit_id) => match state.units.get(unit_id) {
+                    Some(unit) if unit.spotted => state.player_for(unit.side.opponent()).into_iter().collect(),
+                    _ => continue,
+                },
+            };
+            frames.extend(users.into_iter().map(|user_id| (user_id.clone(), payload.clone())));
+        }
+
+        // Snapshots go first, so the rest of the tick applies on top of them
+        frames.sort_by_key(|(_, payload)| !matches!(payload, ws_server_message::Payload::GameSnapshot(_)));
+
+        let sockets = sockets.lock().await;
+        for (user_id, payload) in frames {
+            // Players without a socket miss the frame; they get a snapshot when they return
+            let Some(tx) = sockets.get(&user_id) else {
+                continue;
+            };
+
+            // A snapshot replaces everything the client knew, so counting starts over
+            let sequence = state.sequences.entry(user_id).or_default();
+            if matches!(payload, ws_server_message::Payload::GameSnapshot(_)) {
+                *sequence = 0;
+            }
+            *sequence += 1;
+
+            let msg = WsServerMessage {
+                sequence: *sequence,
+                tick: state.tick,
+                game_time_secs: state.game_time_secs(),
+                payload: Some(payload),
+            };
+            let mut buf = Vec::new();
+            if msg.encode(&mut buf).is_err() {
+                error!("❌ Failed to encode WsServerMessage for session {}", state.session_id);
+                continue;
+            }
+
+            let _ = tx.send(Message::Binary(Bytes::from(buf)));
+        }
+    }
+}
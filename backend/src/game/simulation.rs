@@ -0,0 +1,239 @@
+use redis::TypedCommands;
+use tokio::sync::mpsc::{UnboundedReceiver, error::TryRecvError};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::AppState;
+use crate::game::outbox::Outbox;
+use crate::game::state::SessionState;
+use crate::game::victory::GameOutcome;
+use crate::game::{
+    GameCommand, GameTx, Rejection, TICK_INTERVAL, artillery, combat, connection, engineering, morale, movement, objectives, supply, victory,
+    visibility,
+};
+use crate::models::proto::{CommandRejectionReason, GameEndedEvent, UnitSide, ws_server_message};
+
+/// Spawns the loop that owns `state` for the rest of the game.
+pub fn spawn(state: SessionState, app: AppState) -> GameTx {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(run(state, rx, app));
+    tx
+}
+
+async fn run(mut state: SessionState, mut commands: UnboundedReceiver<GameCommand>, app: AppState) {
+    info!(
+        "🕹️ Session loop started for {} ({} units, {} objectives, {} areas)",
+        state.session_id,
+        state.units.len(),
+        state.objectives.len(),
+        state.areas.len()
+    );
+
+    // Each player starts from the initial state of their own side
+    visibility::refresh(&mut state);
+    let mut outbox = Outbox::default();
+    for side in [UnitSide::Blue, UnitSide::Red] {
+        outbox.send_to_side(side, ws_server_message::Payload::GameState(state.game_state_event(side)));
+    }
+    outbox.flush(&mut state, &app.sockets).await;
+
+    let mut ticker = interval(TICK_INTERVAL);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let dt = TICK_INTERVAL.as_secs_f64();
+
+    loop {
+        ticker.tick().await;
+        let mut outbox = Outbox::default();
+
+        // 1. Apply everything players sent since the previous tick
+        loop {
+            match commands.try_recv() {
+                Ok(command) => apply_command(&mut state, command, &mut outbox),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    info!("🛑 Session loop stopped for {}", state.session_id);
+                    return;
+                }
+            }
+        }
+
+        // 2. Advance the simulation by one fixed step; the game waits while a player is away
+        if state.outcome.is_none() {
+            state.outcome = connection::update(&mut state, dt);
+        }
+        if state.outcome.is_none() && state.away.is_empty() {
+            state.tick += 1;
+            movement::advance(&mut state, dt, &mut outbox);
+            state.reindex_units();
+            artillery::update(&mut state, dt, &mut outbox);
+            combat::resolve(&mut state, dt, &mut outbox);
+            morale::update(&mut state, dt, &mut outbox);
+            engineering::update(&mut state, dt, &mut outbox);
+            objectives::update(&mut state, dt, &mut outbox);
+            supply::update(&mut state, dt, &mut outbox);
+            movement::report(&mut state, &mut outbox);
+            visibility::update(&mut state, &mut outbox);
+            state.outcome = victory::evaluate(&mut state, dt);
+        }
+
+        // 3. Send this tick's updates
+        if let Some(outcome) = state.outcome.clone() {
+            finish(&mut state, outcome, outbox, &app).await;
+            return;
+        }
+        outbox.flush(&mut state, &app.sockets).await;
+    }
+}
+
+fn apply_command(state: &mut SessionState, command: GameCommand, outbox: &mut Outbox) {
+    let (user_id, command_name, result) = match command {
+        GameCommand::MoveUnit { user_id, request } => {
+            let result = authorize_unit(state, &user_id, &request.unit_id)
+                .and_then(|_| movement::issue_order(state, &request, outbox));
+            if result.is_ok() {
+                info!("🚶 {} ordered unit {} to move", user_id, request.unit_id);
+            }
+            (user_id, "move_unit", result)
+        }
+        GameCommand::MoveUnitPath { user_id, request } => {
+            let result = authorize_unit(state, &user_id, &request.unit_id)
+                .and_then(|_| movement::issue_path(state, &request, outbox));
+            if result.is_ok() {
+                info!(
+                    "🧭 {} gave unit {} a {}-waypoint path",
+                    user_id,
+                    request.unit_id,
+                    request.waypoints.len()
+                );
+            }
+            (user_id, "move_unit_path", result)
+        }
+        GameCommand::StopUnit { user_id, request } => {
+            let result = authorize_unit(state, &user_id, &request.unit_id)
+                .and_then(|_| movement::stop(state, &request, outbox));
+            if result.is_ok() {
+                info!("✋ {} stopped unit {}", user_id, request.unit_id);
+            }
+            (user_id, "stop_unit", result)
+        }
+        GameCommand::FireMission { user_id, request } => {
+            let result = authorize_unit(state, &user_id, &request.unit_id)
+                .and_then(|_| artillery::fire_mission(state, &request, outbox));
+            if result.is_ok() {
+                info!(
+                    "💥 {} called a fire mission from unit {} on ({:.5}, {:.5})",
+                    user_id, request.unit_id, request.target_lat, request.target_lon
+                );
+            }
+            (user_id, "fire_mission", result)
+        }
+        GameCommand::Build { user_id, request } => {
+            let result = authorize_unit(state, &user_id, &request.unit_id)
+                .and_then(|_| engineering::build(state, &request, outbox));
+            if result.is_ok() {
+                info!("🏗️ {} ordered unit {} to build a {}", user_id, request.unit_id, request.area_type);
+            }
+            (user_id, "build", result)
+        }
+        GameCommand::BuildUnit { user_id, request } => {
+            let result = player_side(state, &user_id)
+                .and_then(|side| supply::build_unit(state, side, &request, outbox));
+            if result.is_ok() {
+                info!("🏭 {} ordered a {} at {}", user_id, request.unit_type, request.spawn_id);
+            }
+            (user_id, "build_unit", result)
+        }
+        GameCommand::Surrender { user_id } => {
+            let result = player_side(state, &user_id).map(|side| {
+                info!("🏳️ {} surrendered in session {}", user_id, state.session_id);
+                state.outcome.get_or_insert_with(|| victory::surrender(side));
+            });
+            (user_id, "surrender", result)
+        }
+        GameCommand::RequestSnapshot { user_id } => {
+            let result = player_side(state, &user_id).map(|side| {
+                outbox.send_to_user(&user_id, ws_server_message::Payload::GameSnapshot(state.snapshot(side)));
+            });
+            (user_id, "request_snapshot", result)
+        }
+        GameCommand::PlayerDisconnected { user_id } => {
+            connection::disconnected(state, &user_id, outbox);
+            return;
+        }
+        GameCommand::PlayerReconnected { user_id } => {
+            connection::reconnected(state, &user_id, outbox);
+            return;
+        }
+    };
+
+    if let Err(rejection) = result {
+        warn!(
+            "🚫 Rejected {} from {} in session {}: {}",
+            command_name, user_id, state.session_id, rejection.message
+        );
+        outbox.send_to_user(&user_id, rejection.into_payload(&state.session_id, command_name));
+    }
+}
+
+fn player_side(state: &SessionState, user_id: &str) -> Result<UnitSide, Rejection> {
+    state.side_of(user_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::NotInSession, "You are not a player in this session")
+    })
+}
+
+/// Player1 may only command BLUE units and player2 only RED ones, and routing units
+/// take no orders at all.
+fn authorize_unit(state: &SessionState, user_id: &str, unit_id: &str) -> Result<UnitSide, Rejection> {
+    let side = player_side(state, user_id)?;
+    let unit = state.units.get(unit_id).ok_or_else(|| {
+        Rejection::new(CommandRejectionReason::UnitNotFound, format!("Unit {} not found", unit_id))
+    })?;
+
+    if unit.side != side {
+        return Err(Rejection::new(
+            CommandRejectionReason::NotUnitOwner,
+            format!("Unit {} belongs to the other side", unit_id),
+        ));
+    }
+    if unit.routing {
+        return Err(Rejection::new(
+            CommandRejectionReason::UnitRouting,
+            format!("Unit {} is routing and will not take orders", unit_id),
+        ));
+    }
+    Ok(side)
+}
+
+/// Announces the result, marks the session finished and unregisters the loop.
+async fn finish(state: &mut SessionState, outcome: GameOutcome, mut outbox: Outbox, app: &AppState) {
+    let winner_id = outcome
+        .winner
+        .and_then(|side| state.player_for(side))
+        .cloned()
+        .unwrap_or_default();
+
+    info!(
+        "🏁 Session {} ended: {} (winner: {:?})",
+        state.session_id, outcome.reason, outcome.winner
+    );
+
+    outbox.broadcast(ws_server_message::Payload::GameEnded(GameEndedEvent {
+        session_id: state.session_id.clone(),
+        winner_id,
+        reason: outcome.reason,
+    }));
+    outbox.flush(state, &app.sockets).await;
+
+    // The session is over; nothing may find it again, or a later disconnect of one of
+    // its players would end it a second time
+    {
+        let mut redis = app.redis.lock().await;
+        for key in [format!("session:{}", state.session_id), format!("session_users:{}", state.session_id)] {
+            if let Err(e) = redis.del(&key) {
+                warn!("⚠️ Failed to delete {} for finished session: {}", key, e);
+            }
+        }
+    }
+
+    app.games.lock().await.remove(&state.session_id);
+}
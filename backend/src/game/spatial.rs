@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use crate::game::state::UnitState;
+use crate::models::proto::{Position, ScenarioArea};
+use crate::utils::KM_PER_DEG_LAT;
+
+/// Cells per side of the area grid.
+const AREA_GRID_CELLS: usize = 32;
+/// Edge of a unit grid cell in degrees, roughly a kilometre.
+const UNIT_CELL_DEG: f64 = 0.01;
+
+#[derive(Clone, Copy, Debug)]
+struct Bounds {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl Bounds {
+    fn around<'a>(points: impl IntoIterator<Item = &'a Position>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, p| {
+            let b = bounds.unwrap_or(Bounds {
+                min_lat: p.lat,
+                min_lon: p.lon,
+                max_lat: p.lat,
+                max_lon: p.lon,
+            });
+            Some(Bounds {
+                min_lat: b.min_lat.min(p.lat),
+                min_lon: b.min_lon.min(p.lon),
+                max_lat: b.max_lat.max(p.lat),
+                max_lon: b.max_lon.max(p.lon),
+            })
+        })
+    }
+
+    fn union(self, other: Bounds) -> Bounds {
+        Bounds {
+            min_lat: self.min_lat.min(other.min_lat),
+            min_lon: self.min_lon.min(other.min_lon),
+            max_lat: self.max_lat.max(other.max_lat),
+            max_lon: self.max_lon.max(other.max_lon),
+        }
+    }
+
+    fn contains(&self, lat: f64, lon: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&lat) && (self.min_lon..=self.max_lon).contains(&lon)
+    }
+}
+
+/// Fixed grid over the bounding box of every scenario area, built once per session.
+/// Each cell lists the areas whose bounds overlap it, in draw order.
+#[derive(Debug, Default)]
+pub struct AreaIndex {
+    bounds: Option<Bounds>,
+    cells: Vec<Vec<usize>>,
+}
+
+impl AreaIndex {
+    pub fn build(areas: &[ScenarioArea]) -> Self {
+        let area_bounds: Vec<Option<Bounds>> = areas
+            .iter()
+            .map(|area| Bounds::around(area.coordinates.iter().flat_map(|ring| &ring.points)))
+            .collect();
+        let Some(bounds) = area_bounds.iter().flatten().copied().reduce(Bounds::union) else {
+            return Self::default();
+        };
+
+        let mut index = Self {
+            bounds: Some(bounds),
+            cells: vec![Vec::new(); AREA_GRID_CELLS * AREA_GRID_CELLS],
+        };
+        for (area, b) in area_bounds.iter().enumerate() {
+            let Some(b) = b else { continue };
+            let (row0, col0) = index.cell(&bounds, b.min_lat, b.min_lon);
+            let (row1, col1) = index.cell(&bounds, b.max_lat, b.max_lon);
+            for row in row0..=row1 {
+                for col in col0..=col1 {
+                    index.cells[row * AREA_GRID_CELLS + col].push(area);
+                }
+            }
+        }
+        index
+    }
+
+    /// Clamps a point onto the grid.
+    fn cell(&self, bounds: &Bounds, lat: f64, lon: f64) -> (usize, usize) {
+        let scale = |value: f64, min: f64, max: f64| {
+            let t = if max > min { (value - min) / (max - min) } else { 0.0 };
+            ((t * AREA_GRID_CELLS as f64) as usize).min(AREA_GRID_CELLS - 1)
+        };
+        (
+            scale(lat, bounds.min_lat, bounds.max_lat),
+            scale(lon, bounds.min_lon, bounds.max_lon),
+        )
+    }
+
+    /// Areas that may contain the point, in draw order.
+    pub fn candidates_at(&self, lat: f64, lon: f64) -> &[usize] {
+        match &self.bounds {
+            Some(bounds) if bounds.contains(lat, lon) => {
+                let (row, col) = self.cell(bounds, lat, lon);
+                &self.cells[row * AREA_GRID_CELLS + col]
+            }
+            _ => &[],
+        }
+    }
+
+    /// Areas that may touch the segment between two points, in draw order.
+    pub fn candidates_along(&self, from: &Position, to: &Position) -> Vec<usize> {
+        let Some(bounds) = &self.bounds else {
+            return Vec::new();
+        };
+        let (row0, col0) = self.cell(bounds, from.lat.min(to.lat), from.lon.min(to.lon));
+        let (row1, col1) = self.cell(bounds, from.lat.max(to.lat), from.lon.max(to.lon));
+
+        let mut areas: Vec<usize> = (row0..=row1)
+            .flat_map(|row| (col0..=col1).map(move |col| row * AREA_GRID_CELLS + col))
+            .flat_map(|cell| self.cells[cell].iter().copied())
+            .collect();
+        areas.sort_unstable();
+        areas.dedup();
+        areas
+    }
+}
+
+/// Hash grid of unit positions, rebuilt every tick once units have moved.
+#[derive(Debug, Default)]
+pub struct UnitIndex {
+    cells: HashMap<(i64, i64), Vec<String>>,
+    /// Longest sight range of any indexed unit, so "who can see this unit" can be asked
+    /// as a proximity query.
+    pub max_sight_range_km: f64,
+    /// Units with a command radius; there are only ever a handful.
+    pub headquarters: Vec<String>,
+}
+
+fn unit_cell(lat: f64, lon: f64) -> (i64, i64) {
+    ((lat / UNIT_CELL_DEG).floor() as i64, (lon / UNIT_CELL_DEG).floor() as i64)
+}
+
+impl UnitIndex {
+    pub fn build<'a>(units: impl IntoIterator<Item = &'a UnitState>) -> Self {
+        let mut index = Self::default();
+        for unit in units {
+            index
+                .cells
+                .entry(unit_cell(unit.lat, unit.lon))
+                .or_default()
+                .push(unit.id.clone());
+            index.max_sight_range_km = index.max_sight_range_km.max(unit.sight_range_km);
+            if unit.command.is_some() {
+                index.headquarters.push(unit.id.clone());
+            }
+        }
+        index
+    }
+
+    /// Ids of units in the cells covering `radius_km` around a point. Callers still
+    /// check the exact distance, and units removed since the rebuild may be listed.
+    pub fn near(&self, lat: f64, lon: f64, radius_km: f64) -> impl Iterator<Item = &str> {
+        let dlat = radius_km / KM_PER_DEG_LAT;
+        let dlon = radius_km / (KM_PER_DEG_LAT * lat.to_radians().cos().max(0.01));
+        let (row0, col0) = unit_cell(lat - dlat, lon - dlon);
+        let (row1, col1) = unit_cell(lat + dlat, lon + dlon);
+
+        (row0..=row1)
+            .flat_map(move |row| (col0..=col1).map(move |col| (row, col)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::GameConfig;
+    use crate::game::state::SessionState;
+    use crate::models::proto::{Scenario, Unit, UnitSide};
+    use crate::test_support::{position, rectangle};
+
+    fn units(positions: &[(f64, f64)]) -> SessionState {
+        let units = positions
+            .iter()
+            .map(|&(lat, lon)| Unit {
+                id: None,
+                position: Some(position(lat, lon)),
+                unit_key: "INFANTRY".to_string(),
+                side: UnitSide::Blue as i32,
+                icon: String::new(),
+            })
+            .collect();
+        let scenario = Scenario {
+            units,
+            ..Default::default()
+        };
+        SessionState::from_scenario("test".to_string(), "p1".to_string(), None, scenario, GameConfig::default())
+    }
+
+    #[test]
+    fn empty_area_index_has_no_candidates() {
+        let index = AreaIndex::build(&[]);
+        assert!(index.candidates_at(50.0, 14.0).is_empty());
+        assert!(index.candidates_along(&position(50.0, 14.0), &position(50.1, 14.1)).is_empty());
+    }
+
+    #[test]
+    fn areas_are_listed_only_near_their_bounds() {
+        let areas = [rectangle(50.0, 14.0, 50.1, 14.1), rectangle(50.9, 14.9, 51.0, 15.0)];
+        let index = AreaIndex::build(&areas);
+
+        assert_eq!(index.candidates_at(50.05, 14.05), &[0]);
+        assert_eq!(index.candidates_at(50.95, 14.95), &[1]);
+        assert!(index.candidates_at(50.5, 14.5).is_empty());
+        assert!(index.candidates_at(49.0, 14.05).is_empty());
+    }
+
+    #[test]
+    fn overlapping_areas_keep_draw_order() {
+        let areas = [rectangle(50.0, 14.0, 51.0, 15.0), rectangle(50.4, 14.4, 50.6, 14.6)];
+        let index = AreaIndex::build(&areas);
+
+        assert_eq!(index.candidates_at(50.5, 14.5), &[0, 1]);
+    }
+
+    #[test]
+    fn segments_collect_every_area_they_pass() {
+        let areas = [
+            rectangle(50.0, 14.0, 50.1, 14.1),
+            rectangle(50.45, 14.45, 50.55, 14.55),
+            rectangle(50.9, 14.9, 51.0, 15.0),
+        ];
+        let index = AreaIndex::build(&areas);
+
+        let along = index.candidates_along(&position(50.05, 14.05), &position(50.95, 14.95));
+        assert_eq!(along, vec![0, 1, 2]);
+
+        let along = index.candidates_along(&position(50.05, 14.05), &position(50.08, 14.08));
+        assert_eq!(along, vec![0]);
+    }
+
+    #[test]
+    fn nearby_units_are_found_across_cells() {
+        let state = units(&[(50.0, 14.0), (50.0, 14.012), (50.5, 14.5)]);
+        let index = UnitIndex::build(state.units.values());
+
+        let near: Vec<&str> = index.near(50.0, 14.0, 1.5).collect();
+        assert_eq!(near.len(), 2);
+        assert!(near.iter().all(|id| state.units[*id].lon < 14.1));
+        assert_eq!(index.near(50.5, 14.5, 0.1).count(), 1);
+        assert_eq!(index.near(49.0, 13.0, 1.0).count(), 0);
+    }
+}
@@ -0,0 +1,499 @@
+use std::collections::{HashMap, VecDeque};
+
+use mongodb::Database;
+use mongodb::bson::{self, Document, doc, oid::ObjectId};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::game::artillery::Shell;
+use crate::game::config::{GameConfig, RawCommandRadius, RawFireSupport, RawUnitType};
+use crate::game::engineering::Construction;
+use crate::game::{movement, objectives};
+use crate::game::spatial::{AreaIndex, UnitIndex};
+use crate::game::TICK_INTERVAL;
+use crate::game::supply::{self, Production};
+use crate::game::victory::{self, GameOutcome, VictoryTracker};
+use crate::models::proto::{
+    GameSnapshot, GameStateEvent, LiveUnit, Objective, ObjectiveSnapshot, ObjectiveState, Position,
+    Scenario, ScenarioArea, SpawnPoint, UnitSide, UnitSnapshot, VictoryConditions,
+};
+use crate::utils::{haversine_distance, interpolate};
+
+/// Live state of a single unit, owned by the session loop.
+#[derive(Clone, Debug)]
+pub struct UnitState {
+    /// Session-scoped id; scenario ids are never reused between sessions.
+    pub id: String,
+    pub unit_key: String,
+    /// Canonical type from `units-config.json`, e.g. "HEAVY_ARMOUR".
+    pub type_key: String,
+    pub categories: Vec<String>,
+    pub icon: String,
+    pub side: UnitSide,
+    pub lat: f64,
+    pub lon: f64,
+    pub health: u32,
+    pub max_health: u32,
+    pub accuracy: f64,
+    pub damage: u32,
+    pub sight_range_km: f64,
+    /// Kilometres covered per second of game time.
+    pub movement_speed: f64,
+    /// Seconds left until the unit can fire again.
+    pub reload_secs: f64,
+    pub fire_support: Option<RawFireSupport>,
+    pub builds: Vec<String>,
+    pub command: Option<RawCommandRadius>,
+    /// 0..1; the unit routs when it runs out.
+    pub morale: f64,
+    /// 0..1; built up by incoming fire, lowers accuracy and speed.
+    pub suppression: f64,
+    /// Retreating on its own after its morale broke; ignores orders until it rallies.
+    pub routing: bool,
+    /// Morale, suppression and routing as last reported to the players.
+    pub reported_status: (f32, f32, bool),
+    /// Movement as last reported to the players; `None` once they know the unit stands still.
+    pub reported_motion: Option<ReportedMotion>,
+    /// Whether any enemy unit currently has this unit inside its sight range.
+    pub spotted: bool,
+    pub order: Option<MoveOrder>,
+}
+
+/// Waypoints still ahead of a moving unit, walked in order.
+#[derive(Clone, Debug, Default)]
+pub struct MoveOrder {
+    pub waypoints: VecDeque<Position>,
+    /// Seconds left before the unit reacts to the order and sets off.
+    pub delay_secs: f64,
+}
+
+/// Live state of an objective and its capture zone.
+#[derive(Clone, Debug)]
+pub struct ObjectiveControl {
+    /// Scenario objective; its `state` is kept in sync by the capture logic.
+    pub objective: Objective,
+    pub owner: Option<UnitSide>,
+    pub capturing_side: Option<UnitSide>,
+    /// Capture progress from 0 to 1; stays at 1 while the owner holds the objective.
+    pub progress: f64,
+}
+
+impl ObjectiveControl {
+    pub fn new(objective: Objective) -> Self {
+        Self {
+            objective,
+            owner: None,
+            capturing_side: None,
+            progress: 0.0,
+        }
+    }
+
+    /// Objectives from older scenarios may lack an id; their letter is unique too.
+    pub fn id(&self) -> &str {
+        self.objective.id.as_deref().unwrap_or(&self.objective.letter)
+    }
+
+    pub fn state(&self) -> ObjectiveState {
+        ObjectiveState::try_from(self.objective.state).unwrap_or(ObjectiveState::Neutral)
+    }
+
+    /// State and progress the objective rests at while nobody is capturing it.
+    pub fn settled(&self) -> (ObjectiveState, f64) {
+        match self.owner {
+            Some(_) => (ObjectiveState::Captured, 1.0),
+            None => (ObjectiveState::Neutral, 0.0),
+        }
+    }
+}
+
+impl UnitSide {
+    pub fn opponent(self) -> Self {
+        match self {
+            UnitSide::Blue => UnitSide::Red,
+            UnitSide::Red => UnitSide::Blue,
+        }
+    }
+}
+
+impl UnitState {
+    /// A fresh unit of the given type with full health and no orders.
+    pub fn new(unit_type: &RawUnitType, unit_key: &str, icon: &str, side: UnitSide, position: &Position) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            unit_key: unit_key.to_string(),
+            type_key: unit_type.type_key.clone(),
+            categories: unit_type.categories.clone(),
+            icon: icon.to_string(),
+            side,
+            lat: position.lat,
+            lon: position.lon,
+            health: unit_type.health,
+            max_health: unit_type.health,
+            accuracy: unit_type.accuracy as f64,
+            damage: unit_type.damage,
+            sight_range_km: unit_type.sight_range as f64 / 1000.0,
+            movement_speed: unit_type.movement_speed as f64,
+            reload_secs: 0.0,
+            fire_support: unit_type.fire_support,
+            builds: unit_type.builds.clone(),
+            command: unit_type.command,
+            morale: 1.0,
+            suppression: 0.0,
+            routing: false,
+            reported_status: (1.0, 0.0, false),
+            reported_motion: None,
+            spotted: false,
+            order: None,
+        }
+    }
+
+    /// Whether a per-unit area rule names this unit's type key or one of its categories.
+    pub fn matches_class(&self, class: &str) -> bool {
+        self.type_key.eq_ignore_ascii_case(class)
+            || self.categories.iter().any(|c| c.eq_ignore_ascii_case(class))
+    }
+
+    pub fn to_proto(&self) -> LiveUnit {
+        LiveUnit {
+            unit_id: self.id.clone(),
+            position: Some(Position {
+                lat: self.lat,
+                lon: self.lon,
+            }),
+            unit_key: self.unit_key.clone(),
+            side: self.side as i32,
+            icon: self.icon.clone(),
+            health: self.health,
+            max_health: self.max_health,
+            morale: self.morale as f32,
+            suppression: self.suppression as f32,
+            routing: self.routing,
+        }
+    }
+}
+
+/// Movement as clients extrapolate it: from `start`, along `path` at `speed` km per
+/// second, beginning at `started_at_secs` of game time.
+#[derive(Clone, Debug, Default)]
+pub struct ReportedMotion {
+    pub start: Position,
+    pub path: Vec<Position>,
+    pub speed: f64,
+    pub started_at_secs: f64,
+}
+
+impl ReportedMotion {
+    /// Where clients believe the unit is at `time_secs`.
+    pub fn position_at(&self, time_secs: f64) -> Position {
+        let mut budget_km = self.speed * (time_secs - self.started_at_secs).max(0.0);
+        let mut at = self.start;
+        for next in &self.path {
+            let leg_km = haversine_distance(at.lat, at.lon, next.lat, next.lon);
+            if leg_km > budget_km {
+                let t = budget_km / leg_km;
+                return Position {
+                    lat: interpolate(at.lat, next.lat, t),
+                    lon: interpolate(at.lon, next.lon, t),
+                };
+            }
+            budget_km -= leg_km;
+            at = *next;
+        }
+        at
+    }
+
+    /// Length of the whole path in km.
+    pub fn length_km(&self) -> f64 {
+        std::iter::once(&self.start)
+            .chain(&self.path)
+            .zip(&self.path)
+            .map(|(a, b)| haversine_distance(a.lat, a.lon, b.lat, b.lon))
+            .sum()
+    }
+}
+
+/// Everything the session loop needs to advance a game. Nothing else mutates it.
+#[derive(Debug)]
+pub struct SessionState {
+    pub session_id: String,
+    pub player1: String,
+    pub player2: Option<String>,
+    pub tick: u64,
+    pub config: GameConfig,
+    pub rng: StdRng,
+    pub units: HashMap<String, UnitState>,
+    pub objectives: Vec<ObjectiveControl>,
+    /// Fire mission shells still in the air.
+    pub shells: Vec<Shell>,
+    /// Areas engineers are still building.
+    pub constructions: Vec<Construction>,
+    pub areas: Vec<ScenarioArea>,
+    pub area_index: AreaIndex,
+    pub unit_index: UnitIndex,
+    /// Spawn points with ids filled in, so players can name them in build orders.
+    pub spawn_points: Vec<SpawnPoint>,
+    /// Unspent supplies of each side.
+    pub supplies: [f64; 2],
+    /// Units ordered but not yet arrived.
+    pub production: Vec<Production>,
+    /// Seconds until the next resources update.
+    pub resources_timer: f64,
+    /// Disconnected players and the seconds they have left to come back.
+    pub away: HashMap<String, f64>,
+    /// Sequence number of the last frame sent to each player.
+    pub sequences: HashMap<String, u64>,
+    /// Command penalties of each side that has lost its last headquarters.
+    pub command_lost: [Option<RawCommandRadius>; 2],
+    pub victory: VictoryConditions,
+    pub victory_tracker: VictoryTracker,
+    /// Set once the game is decided; the loop ends the game after the current tick.
+    pub outcome: Option<GameOutcome>,
+}
+
+impl SessionState {
+    /// Instantiates a scenario for one session. Units get fresh ids so two sessions
+    /// of the same scenario never share unit state.
+    pub fn from_scenario(
+        session_id: String,
+        player1: String,
+        player2: Option<String>,
+        scenario: Scenario,
+        config: GameConfig,
+    ) -> Self {
+        let units = scenario
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let position = unit.position.as_ref()?;
+                let unit_type = config.unit_type(unit).cloned().unwrap_or_else(|| {
+                    warn!("⚠️ Unknown unit type '{}' ({}), using defaults", unit.unit_key, unit.icon);
+                    RawUnitType::default()
+                });
+                let side = UnitSide::try_from(unit.side).unwrap_or(UnitSide::Blue);
+                let state = UnitState::new(&unit_type, &unit.unit_key, &unit.icon, side, position);
+                Some((state.id.clone(), state))
+            })
+            .collect::<HashMap<_, _>>();
+        let starting_supplies = config.rules.starting_supplies as f64;
+        let victory = scenario
+            .victory
+            .unwrap_or_else(|| victory::default_conditions(units.values()));
+
+        Self {
+            session_id,
+            player1,
+            player2,
+            tick: 0,
+            config,
+            rng: StdRng::from_os_rng(),
+            objectives: scenario
+                .objectives
+                .into_iter()
+                .map(ObjectiveControl::new)
+                .collect(),
+            area_index: AreaIndex::build(&scenario.areas),
+            unit_index: UnitIndex::build(units.values()),
+            units,
+            shells: Vec::new(),
+            constructions: Vec::new(),
+            areas: scenario.areas,
+            spawn_points: scenario
+                .spawn_points
+                .into_iter()
+                .map(|point| SpawnPoint {
+                    id: point.id.or_else(|| Some(Uuid::new_v4().to_string())),
+                    ..point
+                })
+                .collect(),
+            supplies: [starting_supplies; 2],
+            production: Vec::new(),
+            resources_timer: 0.0,
+            away: HashMap::new(),
+            sequences: HashMap::new(),
+            command_lost: [None; 2],
+            victory,
+            victory_tracker: VictoryTracker::default(),
+            outcome: None,
+        }
+    }
+
+    /// Rebuilds the unit proximity index; call after units move, appear or disappear.
+    pub fn reindex_units(&mut self) {
+        self.unit_index = UnitIndex::build(self.units.values());
+    }
+
+    /// Units within `radius_km` of a point.
+    pub fn units_near(&self, lat: f64, lon: f64, radius_km: f64) -> impl Iterator<Item = &UnitState> {
+        self.unit_index
+            .near(lat, lon, radius_km)
+            .filter_map(|unit_id| self.units.get(unit_id))
+            .filter(move |unit| haversine_distance(lat, lon, unit.lat, unit.lon) <= radius_km)
+    }
+
+    /// Users that receive session-wide broadcasts.
+    pub fn players(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.player1).chain(self.player2.as_ref())
+    }
+
+    /// Player1 commands BLUE, player2 commands RED.
+    pub fn player_for(&self, side: UnitSide) -> Option<&String> {
+        match side {
+            UnitSide::Blue => Some(&self.player1),
+            UnitSide::Red => self.player2.as_ref(),
+        }
+    }
+
+    pub fn side_of(&self, user_id: &str) -> Option<UnitSide> {
+        if self.player1 == user_id {
+            Some(UnitSide::Blue)
+        } else if self.player2.as_deref() == Some(user_id) {
+            Some(UnitSide::Red)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `side` may know about `unit`: its own units and spotted enemies.
+    pub fn is_visible_to(&self, unit: &UnitState, side: UnitSide) -> bool {
+        unit.side == side || unit.spotted
+    }
+
+    /// `unit_id` as `side` may learn it from an event: empty unless the unit is its own
+    /// or currently spotted.
+    pub fn id_visible_to(&self, unit_id: &str, side: UnitSide) -> String {
+        match self.units.get(unit_id) {
+            Some(unit) if self.is_visible_to(unit, side) => unit_id.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// The game state as seen by one side.
+    pub fn game_state_event(&self, side: UnitSide) -> GameStateEvent {
+        GameStateEvent {
+            session_id: self.session_id.clone(),
+            units: self
+                .units
+                .values()
+                .filter(|unit| self.is_visible_to(unit, side))
+                .map(UnitState::to_proto)
+                .collect(),
+            objectives: self
+                .objectives
+                .iter()
+                .map(|control| control.objective.clone())
+                .collect(),
+            areas: self.areas.clone(),
+            spawn_points: self
+                .spawn_points
+                .iter()
+                .filter(|point| point.side == side as i32)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Seconds of game time since the start; stands still while the game is paused.
+    pub fn game_time_secs(&self) -> f64 {
+        self.tick as f64 * TICK_INTERVAL.as_secs_f64()
+    }
+
+    /// Everything `side` may know about the game right now: visible units, the orders of
+    /// its own units, objectives, its resources and the game clock.
+    pub fn snapshot(&self, side: UnitSide) -> GameSnapshot {
+        GameSnapshot {
+            session_id: self.session_id.clone(),
+            tick: self.tick,
+            game_time_secs: self.game_time_secs(),
+            units: self
+                .units
+                .values()
+                .filter(|unit| self.is_visible_to(unit, side))
+                .map(|unit| {
+                    let order = unit.order.as_ref().filter(|_| unit.side == side);
+                    UnitSnapshot {
+                        unit: Some(unit.to_proto()),
+                        waypoints: order
+                            .map(|order| order.waypoints.iter().cloned().collect())
+                            .unwrap_or_default(),
+                        order_delay_secs: order.map_or(0.0, |order| order.delay_secs as f32),
+                        movement: unit
+                            .reported_motion
+                            .is_some()
+                            .then(|| movement::movement_started(self, unit, unit.side == side)),
+                    }
+                })
+                .collect(),
+            objectives: self
+                .objectives
+                .iter()
+                .map(|control| {
+                    // A capture the side can't see looks like the objective at rest
+                    let (objective_state, progress, capturing_side) = if objectives::capture_visible_to(self, control, side) {
+                        (control.state(), control.progress, control.capturing_side)
+                    } else {
+                        let (objective_state, progress) = control.settled();
+                        (objective_state, progress, None)
+                    };
+                    ObjectiveSnapshot {
+                        objective_id: control.id().to_string(),
+                        letter: control.objective.letter.clone(),
+                        position: control.objective.position,
+                        state: objective_state as i32,
+                        progress: progress as f32,
+                        owner: control.owner.map(|side| side as i32),
+                        capturing_side: capturing_side.map(|side| side as i32),
+                    }
+                })
+                .collect(),
+            resources: Some(supply::resources(self, side)),
+            areas: self.areas.clone(),
+            spawn_points: self
+                .spawn_points
+                .iter()
+                .filter(|point| point.side == side as i32)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+pub async fn load_scenario(db: &Database, scenario_id: &str) -> Result<Scenario, String> {
+    let obj_id = ObjectId::parse_str(scenario_id)
+        .map_err(|_| format!("Invalid scenario_id: {}", scenario_id))?;
+
+    let doc = db
+        .collection::<Document>("scenarios")
+        .find_one(doc! { "_id": obj_id })
+        .await
+        .map_err(|e| format!("Database error: {}", e))?
+        .ok_or_else(|| format!("Scenario not found with ID {}", scenario_id))?;
+
+    bson::from_document::<Scenario>(doc)
+        .map_err(|e| format!("Failed to decode BSON into Scenario: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reported_motion_is_extrapolated_along_the_path() {
+        let motion = ReportedMotion {
+            start: Position { lat: 0.0, lon: 0.0 },
+            path: vec![Position { lat: 0.0, lon: 0.01 }, Position { lat: 0.01, lon: 0.01 }],
+            speed: 0.1,
+            started_at_secs: 10.0,
+        };
+        let leg_km = haversine_distance(0.0, 0.0, 0.0, 0.01);
+
+        assert_eq!(motion.position_at(5.0), motion.start);
+        let halfway = motion.position_at(10.0 + leg_km / 2.0 / motion.speed);
+        assert!((halfway.lon - 0.005).abs() < 1e-9 && halfway.lat == 0.0);
+        let turned = motion.position_at(10.0 + leg_km * 1.5 / motion.speed);
+        assert!((turned.lat - 0.005).abs() < 1e-6 && (turned.lon - 0.01).abs() < 1e-9);
+        assert_eq!(motion.position_at(1e6), motion.path[1]);
+        assert!((motion.length_km() - 2.0 * leg_km).abs() < 1e-3);
+    }
+}
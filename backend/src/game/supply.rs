@@ -0,0 +1,159 @@
+use tracing::info;
+
+use crate::game::Rejection;
+use crate::game::config::RawUnitType;
+use crate::game::outbox::Outbox;
+use crate::game::state::{SessionState, UnitState};
+use crate::models::proto::{
+    BuildUnitRequest, CommandRejectionReason, Position, ResourcesUpdate, UnitInProduction, UnitSide,
+    UnitSpawnedEvent, ws_server_message,
+};
+
+/// A unit a side has paid for and is waiting on.
+#[derive(Clone, Debug)]
+pub struct Production {
+    pub side: UnitSide,
+    pub unit_type: RawUnitType,
+    pub spawn: Position,
+    pub remaining_secs: f64,
+}
+
+/// Spends supplies on a unit that arrives at the chosen headquarters or spawn point
+/// once its build time is up.
+pub fn build_unit(
+    state: &mut SessionState,
+    side: UnitSide,
+    req: &BuildUnitRequest,
+    outbox: &mut Outbox,
+) -> Result<(), Rejection> {
+    let Some((unit_type, cost)) = state
+        .config
+        .unit_type_by_key(&req.unit_type)
+        .and_then(|unit_type| Some((unit_type.clone(), unit_type.cost?)))
+    else {
+        return Err(Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            format!("{} cannot be produced", req.unit_type),
+        ));
+    };
+
+    let spawn = spawn_position(state, side, &req.spawn_id).ok_or_else(|| {
+        Rejection::new(
+            CommandRejectionReason::InvalidOrder,
+            format!("{} is not one of your headquarters or spawn points", req.spawn_id),
+        )
+    })?;
+
+    let supplies = &mut state.supplies[side as usize];
+    if *supplies < cost as f64 {
+        return Err(Rejection::new(
+            CommandRejectionReason::InsufficientSupplies,
+            format!("{} costs {} supplies; you have {:.0}", req.unit_type, cost, supplies.floor()),
+        ));
+    }
+    *supplies -= cost as f64;
+
+    state.production.push(Production {
+        side,
+        remaining_secs: unit_type.build_time as f64,
+        unit_type,
+        spawn,
+    });
+    outbox.send_to_side(side, resources_update(state, side));
+    Ok(())
+}
+
+/// A friendly headquarters unit or spawn point with the given id.
+fn spawn_position(state: &SessionState, side: UnitSide, spawn_id: &str) -> Option<Position> {
+    let headquarters = state
+        .units
+        .get(spawn_id)
+        .filter(|unit| unit.side == side && unit.command.is_some())
+        .map(|unit| Position {
+            lat: unit.lat,
+            lon: unit.lon,
+        });
+
+    headquarters.or_else(|| {
+        state
+            .spawn_points
+            .iter()
+            .find(|point| point.id.as_deref() == Some(spawn_id) && point.side == side as i32)
+            .and_then(|point| point.position)
+    })
+}
+
+/// Accrues supplies from held objectives, delivers finished units and sends each
+/// player their resources every `resources_interval` seconds.
+pub fn update(state: &mut SessionState, dt: f64, outbox: &mut Outbox) {
+    let per_objective = state.config.rules.supplies_per_objective as f64 * dt;
+    for control in &state.objectives {
+        if let Some(owner) = control.owner {
+            state.supplies[owner as usize] += per_objective;
+        }
+    }
+
+    let mut spawned = false;
+    for mut production in std::mem::take(&mut state.production) {
+        production.remaining_secs -= dt;
+        if production.remaining_secs > 0.0 {
+            state.production.push(production);
+            continue;
+        }
+
+        let unit_type = &production.unit_type;
+        let unit = UnitState::new(unit_type, &unit_type.type_key, &unit_type.icon, production.side, &production.spawn);
+        info!(
+            "🏭 {} arrived for {:?} in session {}",
+            unit_type.type_key, production.side, state.session_id
+        );
+        outbox.send_to_side(
+            production.side,
+            ws_server_message::Payload::UnitSpawned(UnitSpawnedEvent {
+                session_id: state.session_id.clone(),
+                unit: Some(unit.to_proto()),
+            }),
+        );
+        // A new headquarters restores command to a side that had lost it
+        if unit.command.is_some() && state.command_lost[production.side as usize].take().is_some() {
+            info!("📡 Side {:?} regained command in session {}", production.side, state.session_id);
+        }
+        state.units.insert(unit.id.clone(), unit);
+        spawned = true;
+    }
+    if spawned {
+        state.reindex_units();
+    }
+
+    state.resources_timer -= dt;
+    if state.resources_timer <= 0.0 {
+        state.resources_timer += (state.config.rules.resources_interval as f64).max(dt);
+        for side in [UnitSide::Blue, UnitSide::Red] {
+            outbox.send_to_side(side, resources_update(state, side));
+        }
+    }
+}
+
+fn resources_update(state: &SessionState, side: UnitSide) -> ws_server_message::Payload {
+    ws_server_message::Payload::Resources(resources(state, side))
+}
+
+/// Supplies and production queue of one side.
+pub fn resources(state: &SessionState, side: UnitSide) -> ResourcesUpdate {
+    let production: Vec<UnitInProduction> = state
+        .production
+        .iter()
+        .filter(|production| production.side == side)
+        .map(|production| UnitInProduction {
+            unit_type: production.unit_type.type_key.clone(),
+            complete_in_secs: production.remaining_secs.max(0.0) as f32,
+        })
+        .collect();
+
+    ResourcesUpdate {
+        session_id: state.session_id.clone(),
+        supplies: state.supplies[side as usize].floor() as u32,
+        units_in_build: production.len() as u32,
+        production,
+    }
+}
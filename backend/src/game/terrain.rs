@@ -0,0 +1,114 @@
+use crate::{RawArea, RawAreaOverride};
+use crate::game::state::{SessionState, UnitState};
+use crate::models::proto::Position;
+use crate::pathfinding::WeightedArea;
+use crate::utils::{first_blocked_cut, point_in_area, segment_cuts};
+
+/// Terrain modifiers in effect at a single point on the map.
+#[derive(Clone, Copy, Debug)]
+pub struct Terrain {
+    pub movement_speed_modifier: f64,
+    pub accuracy_modifier: f64,
+    pub enemy_miss_chance: f64,
+}
+
+impl Default for Terrain {
+    /// Ground outside every scenario area has no effect.
+    fn default() -> Self {
+        Self {
+            movement_speed_modifier: 1.0,
+            accuracy_modifier: 1.0,
+            enemy_miss_chance: 0.0,
+        }
+    }
+}
+
+impl From<&RawArea> for Terrain {
+    fn from(area: &RawArea) -> Self {
+        Self {
+            movement_speed_modifier: area.movement_speed_modifier as f64,
+            accuracy_modifier: area.accuracy_modifier as f64,
+            enemy_miss_chance: area.enemy_miss_chance as f64,
+        }
+    }
+}
+
+impl Terrain {
+    /// The area's modifiers as they apply to `unit`, with any matching override on top.
+    pub fn for_unit(area: &RawArea, unit: &UnitState) -> Self {
+        let base = Terrain::from(area);
+        let Some(o) = area.override_for(unit) else {
+            return base;
+        };
+
+        Self {
+            movement_speed_modifier: o.movement_speed_modifier.map_or(base.movement_speed_modifier, f64::from),
+            accuracy_modifier: o.accuracy_modifier.map_or(base.accuracy_modifier, f64::from),
+            enemy_miss_chance: o.enemy_miss_chance.map_or(base.enemy_miss_chance, f64::from),
+        }
+    }
+}
+
+/// Looks up the area type under a point. Areas drawn later sit on top of earlier ones.
+pub fn area_type_at(state: &SessionState, lat: f64, lon: f64) -> Option<&RawArea> {
+    state
+        .area_index
+        .candidates_at(lat, lon)
+        .iter()
+        .rev()
+        .map(|&index| &state.areas[index])
+        .find(|area| point_in_area(lat, lon, area))
+        .and_then(|area| state.config.area_type(&area.r#type))
+}
+
+impl RawArea {
+    /// An override naming the unit's type key wins over one naming a category.
+    fn override_for(&self, unit: &UnitState) -> Option<&RawAreaOverride> {
+        self.unit_overrides
+            .iter()
+            .find(|o| o.unit_class.eq_ignore_ascii_case(&unit.type_key))
+            .or_else(|| self.unit_overrides.iter().find(|o| unit.matches_class(&o.unit_class)))
+    }
+
+    pub fn blocks(&self, unit: &UnitState) -> bool {
+        self.impassable || self.impassable_for.iter().any(|class| unit.matches_class(class))
+    }
+}
+
+/// Whether the topmost area at a point is closed to `unit`.
+pub fn is_blocked(state: &SessionState, unit: &UnitState, lat: f64, lon: f64) -> bool {
+    area_type_at(state, lat, lon).is_some_and(|area_type| area_type.blocks(unit))
+}
+
+/// Fraction of the way from `from` to `to` at which `unit` would first enter ground
+/// closed to it. Closed ground it starts in may always be left.
+pub fn blocked_along(state: &SessionState, unit: &UnitState, from: &Position, to: &Position) -> Option<f64> {
+    let areas = state.area_index.candidates_along(from, to);
+    let cuts = segment_cuts(from, to, areas.iter().map(|&index| &state.areas[index]));
+    first_blocked_cut(from, to, &cuts, |lat, lon| is_blocked(state, unit, lat, lon))
+}
+
+/// Terrain modifiers for `unit` if it stood at a point.
+pub fn terrain_at(state: &SessionState, unit: &UnitState, lat: f64, lon: f64) -> Terrain {
+    area_type_at(state, lat, lon)
+        .map(|area_type| Terrain::for_unit(area_type, unit))
+        .unwrap_or_default()
+}
+
+/// Scenario areas weighted by how fast `unit` crosses them, for route planning.
+/// Areas closed to the unit get a zero speed, which the pathfinder never enters. The
+/// order matches `state.areas`, so `state.area_index` indexes the result.
+pub fn weighted_areas<'a>(state: &'a SessionState, unit: &UnitState) -> Vec<WeightedArea<'a>> {
+    state
+        .areas
+        .iter()
+        .map(|area| WeightedArea {
+            area,
+            speed_modifier: match state.config.area_type(&area.r#type) {
+                Some(area_type) if area_type.blocks(unit) => 0.0,
+                Some(area_type) => Terrain::for_unit(area_type, unit).movement_speed_modifier,
+                None => 1.0,
+            },
+        })
+        .collect()
+}
@@ -0,0 +1,260 @@
+use crate::game::state::{SessionState, UnitState};
+use crate::models::proto::{UnitSide, VictoryConditions};
+
+/// Used for scenarios created without their own victory conditions. Wiping out the
+/// enemy only counts when both sides start with units, so one-sided scenarios don't
+/// end on the first tick.
+pub fn default_conditions<'a>(units: impl IntoIterator<Item = &'a UnitState>) -> VictoryConditions {
+    let mut sides = [false; 2];
+    for unit in units {
+        sides[unit.side as usize] = true;
+    }
+
+    VictoryConditions {
+        hold_all_objectives_secs: Some(60.0),
+        destroy_all_enemies: sides == [true, true],
+        time_limit_secs: None,
+        objective_points_per_second: 1.0,
+    }
+}
+
+/// How a game ended. No winner means a draw.
+#[derive(Clone, Debug)]
+pub struct GameOutcome {
+    pub winner: Option<UnitSide>,
+    pub reason: String,
+}
+
+/// Progress towards the scenario's victory conditions.
+#[derive(Clone, Debug, Default)]
+pub struct VictoryTracker {
+    pub elapsed_secs: f64,
+    /// Points per side, indexed by `UnitSide as usize`.
+    pub points: [f64; 2],
+    /// Side currently holding every objective, and for how long.
+    pub holding: Option<(UnitSide, f64)>,
+}
+
+pub fn surrender(side: UnitSide) -> GameOutcome {
+    GameOutcome {
+        winner: Some(side.opponent()),
+        reason: format!("{} surrendered", side_name(side)),
+    }
+}
+
+/// A player stayed disconnected past the reconnect grace period.
+pub fn abandoned(side: UnitSide) -> GameOutcome {
+    GameOutcome {
+        winner: Some(side.opponent()),
+        reason: format!("{} disconnected", side_name(side)),
+    }
+}
+
+/// Scores this tick and checks every enabled condition.
+pub fn evaluate(state: &mut SessionState, dt: f64) -> Option<GameOutcome> {
+    let conditions = state.victory;
+    let tracker = &mut state.victory_tracker;
+    tracker.elapsed_secs += dt;
+
+    for control in &state.objectives {
+        if let Some(owner) = control.owner {
+            tracker.points[owner as usize] += conditions.objective_points_per_second as f64 * dt;
+        }
+    }
+
+    // Hold every objective
+    if let Some(required_secs) = conditions.hold_all_objectives_secs {
+        let first_owner = state.objectives.first().and_then(|control| control.owner);
+        let holder = first_owner
+            .filter(|side| state.objectives.iter().all(|control| control.owner == Some(*side)));
+
+        tracker.holding = match (holder, tracker.holding) {
+            (Some(side), Some((held_by, secs))) if side == held_by => Some((side, secs + dt)),
+            (Some(side), _) => Some((side, dt)),
+            (None, _) => None,
+        };
+
+        if let Some((side, secs)) = tracker.holding
+            && secs >= required_secs as f64
+        {
+            return Some(GameOutcome {
+                winner: Some(side),
+                reason: format!("{} held every objective", side_name(side)),
+            });
+        }
+    }
+
+    // Destroy every enemy unit
+    if conditions.destroy_all_enemies {
+        let alive = |side: UnitSide| state.units.values().any(|unit| unit.side == side);
+        match (alive(UnitSide::Blue), alive(UnitSide::Red)) {
+            (true, true) => {}
+            (false, false) => {
+                return Some(GameOutcome {
+                    winner: None,
+                    reason: "Both sides were destroyed".to_string(),
+                });
+            }
+            (blue_alive, _) => {
+                let winner = if blue_alive { UnitSide::Blue } else { UnitSide::Red };
+                return Some(GameOutcome {
+                    winner: Some(winner),
+                    reason: format!("{} destroyed every enemy unit", side_name(winner)),
+                });
+            }
+        }
+    }
+
+    // Time limit with points
+    if let Some(limit_secs) = conditions.time_limit_secs
+        && tracker.elapsed_secs >= limit_secs as f64
+    {
+        let [blue, red] = tracker.points;
+        let winner = match blue.total_cmp(&red) {
+            std::cmp::Ordering::Greater => Some(UnitSide::Blue),
+            std::cmp::Ordering::Less => Some(UnitSide::Red),
+            std::cmp::Ordering::Equal => None,
+        };
+        return Some(GameOutcome {
+            winner,
+            reason: format!("Time limit reached ({:.0} : {:.0} points)", blue, red),
+        });
+    }
+
+    None
+}
+
+fn side_name(side: UnitSide) -> &'static str {
+    match side {
+        UnitSide::Blue => "Blue",
+        UnitSide::Red => "Red",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::GameConfig;
+    use crate::models::proto::{Objective, Position, Scenario, Unit};
+
+    fn unit(side: UnitSide) -> Unit {
+        Unit {
+            id: None,
+            position: Some(Position { lat: 50.0, lon: 14.0 }),
+            unit_key: "INFANTRY".to_string(),
+            side: side as i32,
+            icon: String::new(),
+        }
+    }
+
+    fn objective(letter: &str) -> Objective {
+        Objective {
+            id: None,
+            letter: letter.to_string(),
+            state: 0,
+            position: Some(Position { lat: 50.0, lon: 14.0 }),
+        }
+    }
+
+    fn session(units: Vec<Unit>, objectives: Vec<Objective>, victory: Option<VictoryConditions>) -> SessionState {
+        let scenario = Scenario {
+            units,
+            objectives,
+            victory,
+            ..Default::default()
+        };
+        SessionState::from_scenario("test".to_string(), "p1".to_string(), None, scenario, GameConfig::default())
+    }
+
+    fn conditions() -> VictoryConditions {
+        VictoryConditions {
+            hold_all_objectives_secs: None,
+            destroy_all_enemies: false,
+            time_limit_secs: None,
+            objective_points_per_second: 1.0,
+        }
+    }
+
+    #[test]
+    fn one_sided_scenarios_do_not_default_to_annihilation() {
+        let state = session(vec![unit(UnitSide::Blue)], Vec::new(), None);
+        assert!(!state.victory.destroy_all_enemies);
+
+        let state = session(vec![unit(UnitSide::Blue), unit(UnitSide::Red)], Vec::new(), None);
+        assert!(state.victory.destroy_all_enemies);
+    }
+
+    #[test]
+    fn destroying_every_enemy_wins() {
+        let victory = VictoryConditions {
+            destroy_all_enemies: true,
+            ..conditions()
+        };
+        let mut state = session(vec![unit(UnitSide::Blue), unit(UnitSide::Red)], Vec::new(), Some(victory));
+        assert!(evaluate(&mut state, 0.1).is_none());
+
+        state.units.retain(|_, unit| unit.side == UnitSide::Blue);
+        let outcome = evaluate(&mut state, 0.1).unwrap();
+        assert_eq!(outcome.winner, Some(UnitSide::Blue));
+
+        state.units.clear();
+        assert_eq!(evaluate(&mut state, 0.1).unwrap().winner, None);
+    }
+
+    #[test]
+    fn holding_every_objective_wins_after_the_required_time() {
+        let victory = VictoryConditions {
+            hold_all_objectives_secs: Some(1.0),
+            ..conditions()
+        };
+        let mut state = session(Vec::new(), vec![objective("A"), objective("B")], Some(victory));
+        state.objectives[0].owner = Some(UnitSide::Red);
+        assert!(evaluate(&mut state, 0.6).is_none());
+
+        state.objectives[1].owner = Some(UnitSide::Red);
+        assert!(evaluate(&mut state, 0.6).is_none());
+        let outcome = evaluate(&mut state, 0.6).unwrap();
+        assert_eq!(outcome.winner, Some(UnitSide::Red));
+    }
+
+    #[test]
+    fn losing_an_objective_resets_the_hold() {
+        let victory = VictoryConditions {
+            hold_all_objectives_secs: Some(1.0),
+            ..conditions()
+        };
+        let mut state = session(Vec::new(), vec![objective("A")], Some(victory));
+        state.objectives[0].owner = Some(UnitSide::Blue);
+        assert!(evaluate(&mut state, 0.8).is_none());
+
+        state.objectives[0].owner = None;
+        assert!(evaluate(&mut state, 0.8).is_none());
+        state.objectives[0].owner = Some(UnitSide::Blue);
+        assert!(evaluate(&mut state, 0.8).is_none());
+    }
+
+    #[test]
+    fn time_limit_goes_to_the_side_with_more_points() {
+        let victory = VictoryConditions {
+            time_limit_secs: Some(2.0),
+            ..conditions()
+        };
+        let mut state = session(Vec::new(), vec![objective("A")], Some(victory));
+        state.objectives[0].owner = Some(UnitSide::Red);
+        assert!(evaluate(&mut state, 1.0).is_none());
+
+        let outcome = evaluate(&mut state, 1.0).unwrap();
+        assert_eq!(outcome.winner, Some(UnitSide::Red));
+        assert_eq!(state.victory_tracker.points, [0.0, 2.0]);
+    }
+
+    #[test]
+    fn equal_points_at_the_time_limit_are_a_draw() {
+        let victory = VictoryConditions {
+            time_limit_secs: Some(1.0),
+            ..conditions()
+        };
+        let mut state = session(Vec::new(), Vec::new(), Some(victory));
+        assert_eq!(evaluate(&mut state, 1.0).unwrap().winner, None);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::game::movement;
+use crate::game::outbox::Outbox;
+use crate::game::state::{SessionState, UnitState};
+use crate::game::terrain::area_type_at;
+use crate::models::proto::{Position, UnitLostEvent, UnitSide, UnitSpottedEvent, ws_server_message};
+use crate::utils::{haversine_distance, interpolate, segment_cuts};
+
+/// Length of the sight line between two points in km, with every stretch that runs
+/// through an area scaled by that area's `line_of_sight_factor`.
+pub fn sight_distance(state: &SessionState, from: &Position, to: &Position) -> f64 {
+    let areas = state.area_index.candidates_along(from, to);
+    let cuts = segment_cuts(from, to, areas.iter().map(|&index| &state.areas[index]));
+
+    // Between two consecutive crossings the line stays inside the same areas
+    let length_km = haversine_distance(from.lat, from.lon, to.lat, to.lon);
+    cuts.windows(2)
+        .map(|stretch| {
+            let mid = (stretch[0] + stretch[1]) / 2.0;
+            let (lat, lon) = (interpolate(from.lat, to.lat, mid), interpolate(from.lon, to.lon, mid));
+            let factor = area_type_at(state, lat, lon).map_or(1.0, |area_type| area_type.line_of_sight_factor as f64);
+            (stretch[1] - stretch[0]) * length_km * factor
+        })
+        .sum()
+}
+
+/// Whether `observer` can see a map position through the terrain in between.
+pub fn can_see_point(state: &SessionState, observer: &UnitState, target: &Position) -> bool {
+    // Terrain only ever shortens sight, so the plain distance rules most pairs out cheaply
+    if haversine_distance(observer.lat, observer.lon, target.lat, target.lon) > observer.sight_range_km {
+        return false;
+    }
+
+    let from = Position {
+        lat: observer.lat,
+        lon: observer.lon,
+    };
+    sight_distance(state, &from, target) <= observer.sight_range_km
+}
+
+/// Whether `observer` can see `target` through the terrain between them.
+pub fn can_see(state: &SessionState, observer: &UnitState, target: &UnitState) -> bool {
+    let to = Position {
+        lat: target.lat,
+        lon: target.lon,
+    };
+    can_see_point(state, observer, &to)
+}
+
+/// Whether any unit of `side` can see a map position.
+pub fn side_can_see(state: &SessionState, side: UnitSide, target: &Position) -> bool {
+    state
+        .units_near(target.lat, target.lon, state.unit_index.max_sight_range_km)
+        .any(|observer| observer.side == side && can_see_point(state, observer, target))
+}
+
+/// Whether any enemy of `unit` has a line of sight to it.
+pub fn is_spotted(state: &SessionState, unit: &UnitState) -> bool {
+    state
+        .units_near(unit.lat, unit.lon, state.unit_index.max_sight_range_km)
+        .any(|observer| observer.side != unit.side && can_see(state, observer, unit))
+}
+
+/// Recomputes which units the enemy can see and tells the enemy player about every change.
+pub fn update(state: &mut SessionState, outbox: &mut Outbox) {
+    let changes: Vec<(String, bool)> = state
+        .units
+        .values()
+        .map(|unit| (unit.id.clone(), is_spotted(state, unit)))
+        .filter(|(unit_id, spotted)| state.units[unit_id].spotted != *spotted)
+        .collect();
+
+    for (unit_id, spotted) in changes {
+        let Some(unit) = state.units.get_mut(&unit_id) else {
+            continue;
+        };
+        unit.spotted = spotted;
+
+        let payload = if spotted {
+            ws_server_message::Payload::UnitSpotted(UnitSpottedEvent {
+                session_id: state.session_id.clone(),
+                unit: Some(unit.to_proto()),
+            })
+        } else {
+            ws_server_message::Payload::UnitLost(UnitLostEvent {
+                session_id: state.session_id.clone(),
+                unit_id: unit_id.clone(),
+            })
+        };
+        outbox.send_to_side(unit.side.opponent(), payload);
+
+        // Moving units come into view mid-way; the enemy has to know where they are heading
+        let unit = &state.units[&unit_id];
+        if spotted && unit.reported_motion.is_some() {
+            outbox.send_to_side(
+                unit.side.opponent(),
+                ws_server_message::Payload::UnitMovementStarted(movement::movement_started(state, unit, false)),
+            );
+        }
+    }
+}
+
+/// Sets the initial visibility without emitting events; the initial state already reflects it.
+pub fn refresh(state: &mut SessionState) {
+    let spotted: Vec<(String, bool)> = state
+        .units
+        .values()
+        .map(|unit| (unit.id.clone(), is_spotted(state, unit)))
+        .collect();
+
+    for (unit_id, spotted) in spotted {
+        if let Some(unit) = state.units.get_mut(&unit_id) {
+            unit.spotted = spotted;
+        }
+    }
+}
@@ -1,15 +1,21 @@
 use redis::TypedCommands;
+mod auth;
+mod game;
 mod models;
+mod pathfinding;
 mod routes;
+#[cfg(test)]
+mod test_support;
 mod utils;
 
 use std::{fs, net::SocketAddr, path::Path as FsPath, sync::Arc};
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use axum::{
     Router,
     body::Bytes,
     extract::{
-        ConnectInfo, Path, State,
+        ConnectInfo, Path, Query, State,
         ws::{Message, WebSocket, WebSocketUpgrade},
     },
     http::{HeaderMap, Method, StatusCode},
@@ -18,60 +24,44 @@ use axum::{
 };
 use axum::extract::ws::Utf8Bytes;
 use futures::{SinkExt, StreamExt};
-use models::proto::{
-    Area as PbArea, AreaList as PbAreaList, Scenario, ScenarioList, ScenarioSummary,
-    UnitType as PbUnitType, UnitTypeKey, UnitTypeList as PbUnitTypeList,
-};
 use mongodb::{
-    Client, Database, bson,
-    bson::{Document, doc, oid::ObjectId, to_bson},
+    Client, Database,
+    bson::{Document, doc, oid::ObjectId},
 };
 use prost::Message as ProstMessage;
 use serde::de::DeserializeOwned;
 use tokio::net::TcpListener;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{error, info};
-use tracing_subscriber;
 use uuid::Uuid;
-use redis::{AsyncCommands, Client as RedisClient, Connection};
-use serde::{Deserialize, Serialize};
+use redis::{Client as RedisClient, Connection};
+use serde::Deserialize;
 use tokio::sync::Mutex;
 use tracing::log::warn;
 use routes::get_unit_types::get_unit_types;
-use crate::models::proto::{ws_client_message, ws_server_message, GameEndedEvent, GameStartedEvent, JoinSessionRequest, JoinSessionResponse, MoveUnitBroadcast, MoveUnitRequest, SessionList, SessionReadyEvent, StartSessionRequest, StartSessionResponse, WsClientMessage, WsServerMessage};
-use crate::utils::{get_unit_position_from_mongo, haversine_distance, interpolate};
+use crate::game::{GameCommand, Games, Rejection};
+use crate::game::config::GameConfig;
+use crate::game::state::{SessionState, load_scenario};
+use crate::models::proto::{ws_client_message, ws_server_message, CommandRejectionReason, GameEndedEvent, BuildRequest, BuildUnitRequest, FireMissionRequest, GameStartedEvent, JoinSessionRequest, JoinSessionResponse, MoveUnitPathRequest, MoveUnitRequest, RequestSnapshot, SessionList, SessionReadyEvent, StartSessionRequest, StartSessionResponse, StopUnitRequest, SurrenderRequest, WsClientMessage, WsServerMessage};
 
 #[derive(Deserialize)]
-struct StartSessionInput {
-    scenario_id: String,
-    user_id: String,
-}
-
-#[derive(Deserialize)]
-struct JoinSessionInput {
+struct StartGameInput {
     session_id: String,
-    user_id: String,
 }
 
-#[derive(Serialize)]
-struct SessionSummary {
-    session_id: String,
-    scenario_id: String,
-    state: String,
-    player1: String,
-    player2: Option<String>,
-}
 #[derive(Deserialize)]
-struct StartGameInput {
-    session_id: String,
+struct WsParams {
+    token: Option<String>,
 }
 type Tx = tokio::sync::mpsc::UnboundedSender<Message>;
+type Sockets = Arc<Mutex<HashMap<String, Tx>>>;
 
 #[derive(Clone)]
 struct AppState {
     db: Arc<Database>,
     redis: Arc<Mutex<Connection>>,
-    sockets: Arc<Mutex<HashMap<String, Tx>>>,
+    sockets: Sockets,
+    games: Games,
 }
 
 #[tokio::main]
@@ -96,6 +86,7 @@ async fn main() {
         db,
         redis: Arc::new(Mutex::new(redis_conn)),
         sockets: Arc::new(Mutex::new(HashMap::new())),
+        games: Arc::new(Mutex::new(HashMap::new())),
     };
 
     let cors = CorsLayer::new()
@@ -105,6 +96,7 @@ async fn main() {
 
     let app = Router::new()
         .route("/ws", get(ws_handler))
+        .route("/api/auth/guest", post(routes::auth_guest::auth_guest))
         .route("/api/unit-types.pb", get(get_unit_types))
         .route("/api/area-types.pb", get(routes::get_area_types::list_area_types_protobuf))
         .route("/api/scenario.pb", post(routes::create_scenario::create_scenario))
@@ -131,18 +123,32 @@ async fn main() {
 
 }
 
-async fn ws_handler(ws: WebSocketUpgrade, ConnectInfo(addr): ConnectInfo<SocketAddr>, State(state): State<AppState>) -> impl IntoResponse {
+async fn ws_handler(
+    ws: WebSocketUpgrade,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Query(params): Query<WsParams>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
     info!("Client connected: {}", addr.ip());
-    ws.on_upgrade(move |socket| handle_socket(socket, state))
+
+    // The socket speaks for the player in games, so it needs a token from /api/auth/guest
+    let token = params.token.unwrap_or_default();
+    let Some(user_id) = auth::resolve_token(&mut *state.redis.lock().await, &token) else {
+        warn!("🚫 Rejected WebSocket from {} without a valid token", addr.ip());
+        return (StatusCode::UNAUTHORIZED, "Missing or unknown token").into_response();
+    };
+
+    ws.on_upgrade(move |socket| handle_socket(socket, state, user_id))
 }
 
-async fn handle_socket(socket: WebSocket, state: AppState) {
-    let user_id = Uuid::new_v4().to_string();
+async fn handle_socket(socket: WebSocket, state: AppState, user_id: String) {
     let (mut sender, mut receiver) = socket.split();
     let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Message>();
 
-    // Store socket sender in memory
-    state.sockets.lock().await.insert(user_id.clone(), tx.clone());
+    // Store socket sender in memory; a newer connection of the same player replaces the old one
+    if state.sockets.lock().await.insert(user_id.clone(), tx.clone()).is_some() {
+        info!("🔁 {} connected again, replacing their previous socket", user_id);
+    }
 
     // Store online status in Redis without expiration
     {
@@ -166,6 +172,9 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
 
     info!("Assigned ID: {}", user_id);
 
+    // A returning player picks up any game still waiting for them
+    rejoin_running_games(&state, &user_id).await;
+
     while let Some(Ok(msg)) = receiver.next().await {
         match msg {
             Message::Binary(bytes) => {
@@ -174,7 +183,28 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
                         if let Some(payload) = client_msg.payload {
                             match payload {
                                 ws_client_message::Payload::MoveUnit(req) => {
-                                    handle_move_unit(&state, req).await;
+                                    handle_move_unit(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::MoveUnitPath(req) => {
+                                    handle_move_unit_path(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::StopUnit(req) => {
+                                    handle_stop_unit(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::FireMission(req) => {
+                                    handle_fire_mission(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::Build(req) => {
+                                    handle_build(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::BuildUnit(req) => {
+                                    handle_build_unit(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::RequestSnapshot(req) => {
+                                    handle_request_snapshot(&state, &user_id, req).await;
+                                }
+                                ws_client_message::Payload::Surrender(req) => {
+                                    handle_surrender(&state, &user_id, req).await;
                                 }
                                 _ => {
                                     warn!("Unhandled WsClientMessage payload");
@@ -193,8 +223,19 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
     }
 
 
-    // Cleanup on disconnect
-    state.sockets.lock().await.remove(&user_id);
+    // Cleanup on disconnect, unless the player has already connected again elsewhere
+    {
+        let mut sockets = state.sockets.lock().await;
+        match sockets.get(&user_id) {
+            Some(current) if current.same_channel(&tx) => {
+                sockets.remove(&user_id);
+            }
+            _ => {
+                info!("{} closed an old socket", user_id);
+                return;
+            }
+        }
+    }
 
     {
         let mut redis = state.redis.lock().await;
@@ -203,13 +244,17 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
             warn!("❌ Failed to delete online status for {}: {}", user_id, e);
         }
 
-        cleanup_user_sessions(&user_id, &mut *redis, &state.sockets).await;
+        cleanup_user_sessions(&user_id, &mut *redis, &state.sockets, &state.games, true).await;
     }
 
     info!("{} disconnected", user_id);
 }
 
 pub fn load_configs_from_file<T: DeserializeOwned>(path: &FsPath) -> Result<Vec<T>, String> {
+    load_config_from_file(path)
+}
+
+pub fn load_config_from_file<T: DeserializeOwned>(path: &FsPath) -> Result<T, String> {
     let content =
         fs::read_to_string(path).map_err(|e| format!("Failed to read file {:?}: {}", path, e))?;
     serde_json::from_str(&content).map_err(|e| format!("Failed to parse JSON in {:?}: {}", path, e))
@@ -232,7 +277,7 @@ pub fn load_configs_from_dir<T: DeserializeOwned>(dir: &FsPath) -> Result<Vec<T>
 }
 
 
-#[derive(serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 struct RawArea {
     pub name: String,
     pub description: String,
@@ -240,13 +285,38 @@ struct RawArea {
     pub movement_speed_modifier: f32,
     pub accuracy_modifier: f32,
     pub enemy_miss_chance: f32,
+    #[serde(default)]
+    pub impassable: bool,
+    #[serde(default)]
+    pub impassable_for: Vec<String>,
+    #[serde(default)]
+    pub unit_overrides: Vec<RawAreaOverride>,
+    #[serde(default = "clear_line_of_sight")]
+    pub line_of_sight_factor: f32,
+    /// Seconds an engineer needs to build this area; areas without one cannot be built.
+    #[serde(default)]
+    pub build_time: Option<f32>,
+}
+
+fn clear_line_of_sight() -> f32 {
+    1.0
+}
+
+/// Modifiers for one unit type key or category inside an area; unset values fall back
+/// to the area's own.
+#[derive(Clone, Debug, serde::Deserialize)]
+struct RawAreaOverride {
+    pub unit_class: String,
+    pub movement_speed_modifier: Option<f32>,
+    pub accuracy_modifier: Option<f32>,
+    pub enemy_miss_chance: Option<f32>,
 }
 
 // Session creation
-async fn start_session(State(state): State<AppState>, body: Bytes) -> impl IntoResponse {
+async fn start_session(State(state): State<AppState>, headers: HeaderMap, body: Bytes) -> impl IntoResponse {
     info!("📨 Received POST /api/session/start ({} bytes)", body.len());
 
-    let request = match StartSessionRequest::decode(&*body) {
+    let mut request = match StartSessionRequest::decode(&*body) {
         Ok(r) => {
             info!(
                 "✅ Decoded StartSessionRequest: user_id={}, scenario_id={}",
@@ -260,6 +330,11 @@ async fn start_session(State(state): State<AppState>, body: Bytes) -> impl IntoR
         }
     };
 
+    request.user_id = match auth::request_user(&mut *state.redis.lock().await, &headers) {
+        Ok(user_id) => user_id,
+        Err(status) => return (status, "Missing or unknown token").into_response(),
+    };
+
     let scenario_obj_id = match ObjectId::parse_str(&request.scenario_id) {
         Ok(id) => id,
         Err(_) => {
@@ -355,10 +430,10 @@ async fn start_session(State(state): State<AppState>, body: Bytes) -> impl IntoR
 }
 
 
-async fn join_session(State(state): State<AppState>, body: Bytes) -> impl IntoResponse {
+async fn join_session(State(state): State<AppState>, headers: HeaderMap, body: Bytes) -> impl IntoResponse {
     info!("📨 Received POST /api/session/join ({} bytes)", body.len());
 
-    let request = match JoinSessionRequest::decode(&*body) {
+    let mut request = match JoinSessionRequest::decode(&*body) {
         Ok(r) => {
             info!("✅ Decoded JoinSessionRequest: session_id={}, user_id={}", r.session_id, r.user_id);
             r
@@ -372,6 +447,11 @@ async fn join_session(State(state): State<AppState>, body: Bytes) -> impl IntoRe
     let key = format!("session:{}", request.session_id);
     let mut redis = state.redis.lock().await;
 
+    request.user_id = match auth::request_user(&mut redis, &headers) {
+        Ok(user_id) => user_id,
+        Err(status) => return (status, "Missing or unknown token").into_response(),
+    };
+
     // 🚫 Check if the session exists
     if !redis.exists::<_>(&key).unwrap_or(false) {
         warn!("🚫 Session '{}' does not exist", request.session_id);
@@ -419,19 +499,20 @@ async fn join_session(State(state): State<AppState>, body: Bytes) -> impl IntoRe
     }
 
     // Notify player1 via WebSocket
-    if let Some(player1_id) = player1_opt {
-        if let Some(tx) = state.sockets.lock().await.get(&player1_id) {
-            let message = WsServerMessage {
-                payload: Some(ws_server_message::Payload::SessionReady(SessionReadyEvent {
-                    session_id: request.session_id.clone(),
-                    player2: request.user_id.clone(),
-                })),
-            };
-            let mut buf = Vec::new();
-            if message.encode(&mut buf).is_ok() {
-                let _ = tx.send(Message::Binary(Bytes::from(buf)));
-                info!("📢 Sent SessionReadyEvent to player1 '{}'", player1_id);
-            }
+    if let Some(player1_id) = player1_opt
+        && let Some(tx) = state.sockets.lock().await.get(&player1_id)
+    {
+        let message = WsServerMessage {
+            payload: Some(ws_server_message::Payload::SessionReady(SessionReadyEvent {
+                session_id: request.session_id.clone(),
+                player2: request.user_id.clone(),
+            })),
+            ..Default::default()
+        };
+        let mut buf = Vec::new();
+        if message.encode(&mut buf).is_ok() {
+            let _ = tx.send(Message::Binary(Bytes::from(buf)));
+            info!("📢 Sent SessionReadyEvent to player1 '{}'", player1_id);
         }
     }
 
@@ -447,12 +528,22 @@ async fn join_session(State(state): State<AppState>, body: Bytes) -> impl IntoRe
 }
 
 // Disconnect user and clean up session if empty
-async fn disconnect_user(State(state): State<AppState>, Path(user_id): Path<String>) -> impl IntoResponse {
+async fn disconnect_user(State(state): State<AppState>, headers: HeaderMap, Path(user_id): Path<String>) -> impl IntoResponse {
     info!("POST /api/session/disconnect/{}", user_id);
     let mut redis = state.redis.lock().await;
 
-    cleanup_user_sessions(&user_id, &mut *redis, &state.sockets).await;
-    
+    // 🚫 Players can only disconnect themselves
+    match auth::request_user(&mut redis, &headers) {
+        Ok(caller) if caller == user_id => {}
+        Ok(caller) => {
+            warn!("🚫 {} tried to disconnect {}", caller, user_id);
+            return (StatusCode::FORBIDDEN, "Cannot disconnect another user").into_response();
+        }
+        Err(status) => return (status, "Missing or unknown token").into_response(),
+    }
+
+    cleanup_user_sessions(&user_id, &mut *redis, &state.sockets, &state.games, false).await;
+
     if let Err(e) = redis.del(format!("online:{}", user_id)) {
         warn!("❌ Failed to delete online status for {}: {}", user_id, e);
     }
@@ -497,7 +588,7 @@ async fn list_sessions(State(state): State<AppState>) -> impl IntoResponse {
     (headers, buf).into_response()
 }
 
-async fn start_game(State(state): State<AppState>, body: Bytes) -> impl IntoResponse {
+async fn start_game(State(state): State<AppState>, headers: HeaderMap, body: Bytes) -> impl IntoResponse {
     let input = match serde_json::from_slice::<StartGameInput>(&body) {
         Ok(data) => data,
         Err(e) => {
@@ -507,20 +598,64 @@ async fn start_game(State(state): State<AppState>, body: Bytes) -> impl IntoResp
 
     info!("🎮 Starting game for session: {}", input.session_id);
 
-    let mut redis = state.redis.lock().await;
-    let user_set_key = format!("session_users:{}", input.session_id);
-    let users: HashSet<String> = match redis.smembers(&user_set_key) {
-        Ok(set) => set,
+    let (caller, session_data, users): (String, HashMap<String, String>, HashSet<String>) = {
+        let mut redis = state.redis.lock().await;
+        let caller = match auth::request_user(&mut redis, &headers) {
+            Ok(user_id) => user_id,
+            Err(status) => return (status, "Missing or unknown token").into_response(),
+        };
+        let session_data = redis.hgetall(format!("session:{}", input.session_id)).unwrap_or_default();
+        let user_set_key = format!("session_users:{}", input.session_id);
+        match redis.smembers(&user_set_key) {
+            Ok(set) => (caller, session_data, set),
+            Err(e) => {
+                error!("Failed to get session users: {}", e);
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to get session users").into_response();
+            }
+        }
+    };
+
+    let (Some(scenario_id), Some(player1)) = (session_data.get("scenario_id"), session_data.get("player1")) else {
+        warn!("🚫 Session '{}' does not exist", input.session_id);
+        return (StatusCode::NOT_FOUND, "Session does not exist").into_response();
+    };
+
+    // 🚫 Only the host starts the game
+    if *player1 != caller {
+        warn!("🚫 {} tried to start session '{}' hosted by {}", caller, input.session_id, player1);
+        return (StatusCode::FORBIDDEN, "Only the host can start the game").into_response();
+    }
+
+    // 🗺️ Instantiate the scenario for this session
+    let scenario = match load_scenario(&state.db, scenario_id).await {
+        Ok(scenario) => scenario,
         Err(e) => {
-            error!("Failed to get session users: {}", e);
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to get session users").into_response();
+            error!("❌ Failed to load scenario {}: {}", scenario_id, e);
+            return (StatusCode::INTERNAL_SERVER_ERROR, e).into_response();
         }
     };
 
+    let config = match GameConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            error!("❌ Failed to load game configs: {}", e);
+            return (StatusCode::INTERNAL_SERVER_ERROR, e).into_response();
+        }
+    };
+
+    let session_state = SessionState::from_scenario(
+        input.session_id.clone(),
+        player1.clone(),
+        session_data.get("player2").cloned(),
+        scenario,
+        config,
+    );
+
     let message = WsServerMessage {
         payload: Some(ws_server_message::Payload::GameStarted(GameStartedEvent {
             session_id: input.session_id.clone(),
         })),
+        ..Default::default()
     };
 
     let mut buf = Vec::new();
@@ -528,6 +663,13 @@ async fn start_game(State(state): State<AppState>, body: Bytes) -> impl IntoResp
         return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to encode GameStartedEvent").into_response();
     }
 
+    // Claim the session before telling anyone, so a second request can't start it twice
+    let mut games = state.games.lock().await;
+    let Entry::Vacant(entry) = games.entry(input.session_id.clone()) else {
+        warn!("❌ Game for session '{}' is already running", input.session_id);
+        return (StatusCode::CONFLICT, "Game already started").into_response();
+    };
+
     let txs = state.sockets.lock().await;
     let count = users.iter().filter(|uid| {
         if let Some(tx) = txs.get(*uid) {
@@ -538,6 +680,16 @@ async fn start_game(State(state): State<AppState>, body: Bytes) -> impl IntoResp
         }
     }).count();
 
+    drop(txs);
+
+    // 🕹️ The loop sends the initial game state right after GameStartedEvent
+    entry.insert(game::simulation::spawn(session_state, state.clone()));
+    drop(games);
+
+    if let Err(e) = state.redis.lock().await.hset(format!("session:{}", input.session_id), "state", "started") {
+        warn!("⚠️ Failed to mark session {} as started: {}", input.session_id, e);
+    }
+
     info!("✅ Notified {} players about game start", count);
 
     (StatusCode::OK, "Game started").into_response()
@@ -545,70 +697,118 @@ async fn start_game(State(state): State<AppState>, body: Bytes) -> impl IntoResp
 
 
 // Helper to clean up user from sessions and remove empty sessions
-async fn cleanup_user_sessions(user_id: &str, redis: &mut impl TypedCommands, sockets: &Arc<Mutex<HashMap<String, Tx>>>) {
+// Sessions the user is a member of
+fn user_sessions(user_id: &str, redis: &mut impl TypedCommands) -> Vec<String> {
     let keys: Vec<String> = redis.keys("session_users:*").unwrap_or_default();
+    keys.into_iter()
+        .filter(|key| redis.sismember(key, user_id).unwrap_or(false))
+        .filter_map(|key| key.strip_prefix("session_users:").map(str::to_string))
+        .collect()
+}
 
-    for key in keys {
-        if redis.sismember(&key, user_id).unwrap_or(false) {
-            info!("Removing user {} from set {}", user_id, key);
-            let _ = redis.srem::<_, _>(&key, user_id);
+async fn rejoin_running_games(state: &AppState, user_id: &str) {
+    let sessions = user_sessions(user_id, &mut *state.redis.lock().await);
+    let games = state.games.lock().await;
 
-            let session_id = key.strip_prefix("session_users:").unwrap_or("").to_string();
+    for session_id in sessions {
+        if let Some(game) = games.get(&session_id) {
+            info!("🔁 {} rejoining running game {}", user_id, session_id);
+            let _ = game.send(GameCommand::PlayerReconnected {
+                user_id: user_id.to_string(),
+            });
+        }
+    }
+}
 
-            // Get session info
-            let session_key = format!("session:{}", session_id);
-            let session_data = redis.hgetall::<_>(&session_key).unwrap_or_default();
+// Remove the user from their sessions. With `grace`, running games are only told the
+// player dropped out, so they can still come back before the game ends.
+async fn cleanup_user_sessions(user_id: &str, redis: &mut impl TypedCommands, sockets: &Sockets, games: &Games, grace: bool) {
+    for session_id in user_sessions(user_id, redis) {
+        let key = format!("session_users:{}", session_id);
 
-            let maybe_opponent = if session_data.get("player1") == Some(&user_id.to_string()) {
-                session_data.get("player2").cloned()
-            } else if session_data.get("player2") == Some(&user_id.to_string()) {
-                session_data.get("player1").cloned()
-            } else {
-                None
+        if grace && let Some(game) = games.lock().await.get(&session_id) {
+            let _ = game.send(GameCommand::PlayerDisconnected {
+                user_id: user_id.to_string(),
+            });
+            continue;
+        }
+
+        info!("Removing user {} from set {}", user_id, key);
+        let _ = redis.srem::<_, _>(&key, user_id);
+
+        // Stop the running game, if any
+        games.lock().await.remove(&session_id);
+
+        // Get session info
+        let session_key = format!("session:{}", session_id);
+        let session_data = redis.hgetall::<_>(&session_key).unwrap_or_default();
+
+        let maybe_opponent = if session_data.get("player1") == Some(&user_id.to_string()) {
+            session_data.get("player2").cloned()
+        } else if session_data.get("player2") == Some(&user_id.to_string()) {
+            session_data.get("player1").cloned()
+        } else {
+            None
+        };
+
+        if let Some(opponent_id) = maybe_opponent {
+            info!("User {} disconnected, notifying opponent {}", user_id, opponent_id);
+
+            let msg = WsServerMessage {
+                payload: Some(ws_server_message::Payload::GameEnded(GameEndedEvent {
+                    session_id: session_id.clone(),
+                    winner_id: opponent_id.clone(),
+                    reason: format!("Player {} disconnected", user_id),
+                })),
+                ..Default::default()
             };
 
-            if let Some(opponent_id) = maybe_opponent {
-                info!("User {} disconnected, notifying opponent {}", user_id, opponent_id);
-
-                let msg = WsServerMessage {
-                    payload: Some(ws_server_message::Payload::GameEnded(GameEndedEvent {
-                        session_id: session_id.clone(),
-                        winner_id: opponent_id.clone(),
-                        reason: format!("Player {} disconnected", user_id),
-                    })),
-                };
-
-                let mut buf = Vec::new();
-                if msg.encode(&mut buf).is_ok() {
-                    if let Some(tx) = sockets.lock().await.get(&opponent_id) {
-                        let _ = tx.send(Message::Binary(Bytes::from(buf)));
-                        info!("✅ Notified {} about win due to opponent disconnect", opponent_id);
-                    } else {
-                        warn!("⚠️ Socket for opponent {} not found", opponent_id);
-                    }
+            let mut buf = Vec::new();
+            if msg.encode(&mut buf).is_ok() {
+                if let Some(tx) = sockets.lock().await.get(&opponent_id) {
+                    let _ = tx.send(Message::Binary(Bytes::from(buf)));
+                    info!("✅ Notified {} about win due to opponent disconnect", opponent_id);
                 } else {
-                    error!("❌ Failed to encode GameEndedEvent");
+                    warn!("⚠️ Socket for opponent {} not found", opponent_id);
                 }
+            } else {
+                error!("❌ Failed to encode GameEndedEvent");
             }
+        }
 
-            // Clean up the session if empty
-            if redis.scard::<_>(&key).unwrap_or(1) == 0 {
-                info!("Session {} is empty. Cleaning up.", session_id);
-                let _ = redis.del(&session_key);
-                let _ = redis.del(&key);
-            }
+        // Clean up the session if empty
+        if redis.scard::<_>(&key).unwrap_or(1) == 0 {
+            info!("Session {} is empty. Cleaning up.", session_id);
+            let _ = redis.del(&session_key);
+            let _ = redis.del(&key);
         }
     }
 }
 
-async fn close_session(State(state): State<AppState>, Path(session_id): Path<String>) -> impl IntoResponse {
+async fn close_session(State(state): State<AppState>, headers: HeaderMap, Path(session_id): Path<String>) -> impl IntoResponse {
     info!("🗑️ Closing session: {}", session_id);
 
     let mut redis = state.redis.lock().await;
 
+    let caller = match auth::request_user(&mut redis, &headers) {
+        Ok(user_id) => user_id,
+        Err(status) => return (status, "Missing or unknown token").into_response(),
+    };
+
     let session_key = format!("session:{}", session_id);
     let user_set_key = format!("session_users:{}", session_id);
 
+    // 🚫 Only the host closes the session
+    let session_data: HashMap<String, String> = redis.hgetall(&session_key).unwrap_or_default();
+    let Some(host) = session_data.get("player1") else {
+        warn!("🚫 Session '{}' does not exist", session_id);
+        return (StatusCode::NOT_FOUND, "Session does not exist").into_response();
+    };
+    if *host != caller {
+        warn!("🚫 {} tried to close session '{}' hosted by {}", caller, session_id, host);
+        return (StatusCode::FORBIDDEN, "Only the host can close the session").into_response();
+    }
+
     // Get users in the session before deletion
     let users: HashSet<String> = redis.smembers(&user_set_key).unwrap_or_default();
 
@@ -616,6 +816,9 @@ async fn close_session(State(state): State<AppState>, Path(session_id): Path<Str
     let _ = redis.del(&session_key);
     let _ = redis.del(&user_set_key);
 
+    // Stop the running game, if any
+    state.games.lock().await.remove(&session_id);
+
     // Notify all users still connected
     let sockets = state.sockets.lock().await;
 
@@ -625,6 +828,7 @@ async fn close_session(State(state): State<AppState>, Path(session_id): Path<Str
             winner_id: "".to_string(),
             reason: "Session closed by host".to_string(),
         })),
+        ..Default::default()
     };
 
     let mut buf = Vec::new();
@@ -647,8 +851,8 @@ async fn get_session_by_id(
     let key = format!("session:{}", session_id);
     let mut redis = state.redis.lock().await;
 
-    let exists = match redis.exists::<_>(&key) {
-        Ok(true) => true,
+    match redis.exists::<_>(&key) {
+        Ok(true) => {}
         Ok(false) => return (StatusCode::NOT_FOUND, "Session not found").into_response(),
         Err(e) => {
             error!("Redis error: {}", e);
@@ -683,95 +887,92 @@ async fn get_session_by_id(
     (headers, Bytes::from(buf)).into_response()
 }
 
-async fn handle_move_unit(state: &AppState, req: MoveUnitRequest) {
-    let state = state.clone();
-    tokio::spawn(async move {
-        perform_unit_movement(state, req).await;
-    });
+async fn handle_move_unit(state: &AppState, user_id: &str, req: MoveUnitRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::MoveUnit {
+        user_id: user_id.to_string(),
+        request: req,
+    };
+    dispatch_game_command(state, user_id, &session_id, "move_unit", command).await;
+}
+
+async fn handle_move_unit_path(state: &AppState, user_id: &str, req: MoveUnitPathRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::MoveUnitPath {
+        user_id: user_id.to_string(),
+        request: req,
+    };
+    dispatch_game_command(state, user_id, &session_id, "move_unit_path", command).await;
 }
-async fn perform_unit_movement(state: AppState, req: MoveUnitRequest) {
-    use crate::models::proto::ws_server_message;
-    use tokio::time::{sleep, Duration};
 
-    let redis_key = format!("unit_pos:{}:{}", req.session_id, req.unit_id);
+async fn handle_stop_unit(state: &AppState, user_id: &str, req: StopUnitRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::StopUnit {
+        user_id: user_id.to_string(),
+        request: req,
+    };
+    dispatch_game_command(state, user_id, &session_id, "stop_unit", command).await;
+}
 
-    // 1. Load current position
-    let (start_lat, start_lon) = {
-        let mut redis = state.redis.lock().await;
+async fn handle_fire_mission(state: &AppState, user_id: &str, req: FireMissionRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::FireMission {
+        user_id: user_id.to_string(),
+        request: req,
+    };
+    dispatch_game_command(state, user_id, &session_id, "fire_mission", command).await;
+}
 
-        let pos_map: HashMap<String, String> = redis.hgetall(&redis_key).unwrap_or_default();
-        match (pos_map.get("lat"), pos_map.get("lon")) {
-            (Some(lat_str), Some(lon_str)) => {
-                let lat = lat_str.parse::<f64>().unwrap_or(req.target_lat);
-                let lon = lon_str.parse::<f64>().unwrap_or(req.target_lon);
-                (lat, lon)
-            }
-            _ => {
-                warn!("🔁 Redis missing unit position for {}, using fallback", req.unit_id);
-                match get_unit_position_from_mongo(&state.db, &req.unit_id).await {
-                    Some((lat, lon)) => {
-                        let _ = redis.hset_multiple(&redis_key, &[("lat", lat), ("lon", lon)]);
-                        (lat, lon)
-                    }
-                    None => (req.target_lat, req.target_lon),
-                }
-            }
-        }
+async fn handle_build(state: &AppState, user_id: &str, req: BuildRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::Build {
+        user_id: user_id.to_string(),
+        request: req,
     };
+    dispatch_game_command(state, user_id, &session_id, "build", command).await;
+}
 
-    // 2. Get unit speed
-    let unit_types: Vec<serde_json::Value> =
-        crate::load_configs_from_file(std::path::Path::new("../shared/configs/units-config.json"))
-            .unwrap_or_default();
+async fn handle_build_unit(state: &AppState, user_id: &str, req: BuildUnitRequest) {
+    let session_id = req.session_id.clone();
+    let command = GameCommand::BuildUnit {
+        user_id: user_id.to_string(),
+        request: req,
+    };
+    dispatch_game_command(state, user_id, &session_id, "build_unit", command).await;
+}
 
-    let speed = unit_types
-        .iter()
-        .find(|t| t.get("icon").unwrap_or(&serde_json::Value::Null).as_str() == Some(&req.unit_id))
-        .and_then(|t| t.get("movement_speed").and_then(|s| s.as_f64()))
-        .unwrap_or(1.0);
+async fn handle_request_snapshot(state: &AppState, user_id: &str, req: RequestSnapshot) {
+    let command = GameCommand::RequestSnapshot {
+        user_id: user_id.to_string(),
+    };
+    dispatch_game_command(state, user_id, &req.session_id, "request_snapshot", command).await;
+}
 
-    // 3. Interpolate over time
-    let distance_km = haversine_distance(start_lat, start_lon, req.target_lat, req.target_lon);
-    let duration_secs = distance_km / speed.max(0.01);
-    let steps = (duration_secs * 10.0).ceil() as usize;
+async fn handle_surrender(state: &AppState, user_id: &str, req: SurrenderRequest) {
+    let command = GameCommand::Surrender {
+        user_id: user_id.to_string(),
+    };
+    dispatch_game_command(state, user_id, &req.session_id, "surrender", command).await;
+}
 
-    if steps == 0 {
+// Forward a command to the session loop, or tell the sender the game is not running
+async fn dispatch_game_command(state: &AppState, user_id: &str, session_id: &str, command_name: &str, command: GameCommand) {
+    if let Some(game) = state.games.lock().await.get(session_id) {
+        let _ = game.send(command);
         return;
     }
 
-    for step in 1..=steps {
-        let t = step as f64 / steps as f64;
-        let lat = interpolate(start_lat, req.target_lat, t);
-        let lon = interpolate(start_lon, req.target_lon, t);
-
-        let msg = WsServerMessage {
-            payload: Some(ws_server_message::Payload::UnitMoved(MoveUnitBroadcast {
-                session_id: req.session_id.clone(),
-                unit_id: req.unit_id.clone(),
-                target_lat: lat,
-                target_lon: lon,
-            })),
-        };
-
-        let mut buf = Vec::new();
-        if msg.encode(&mut buf).is_ok() {
-            let users: HashSet<String> = {
-                let mut redis = state.redis.lock().await;
-                redis.smembers(format!("session_users:{}", req.session_id)).unwrap_or_default()
-            };
-
-            let sockets = state.sockets.lock().await;
-            for user_id in users {
-                if let Some(tx) = sockets.get(&user_id) {
-                    let _ = tx.send(Message::Binary(Bytes::from(buf.clone())));
-                }
-            }
-        }
+    warn!("⚠️ {} for session {} without a running game", command_name, session_id);
 
-        sleep(Duration::from_millis(100)).await;
+    let rejection = Rejection::new(CommandRejectionReason::GameNotStarted, "The game has not started");
+    let msg = WsServerMessage {
+        payload: Some(rejection.into_payload(session_id, command_name)),
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    if msg.encode(&mut buf).is_ok()
+        && let Some(tx) = state.sockets.lock().await.get(user_id)
+    {
+        let _ = tx.send(Message::Binary(Bytes::from(buf)));
     }
-
-    // 4. Save final position
-    let mut redis = state.redis.lock().await;
-    let _ = redis.hset_multiple(&redis_key, &[("lat", req.target_lat), ("lon", req.target_lon)]);
 }
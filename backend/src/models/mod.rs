@@ -1,48 +1,26 @@
-use serde::{Deserialize, Serialize};
-use uuid::Uuid;
-
+/// Generated protobuf types. Each package gets its own module so cross-package
+/// references resolve, and everything is re-exported flat under `proto`.
+#[allow(dead_code)]
 pub mod proto {
-    include!(concat!(env!("OUT_DIR"), "/models.rs"));
-    include!(concat!(env!("OUT_DIR"), "/area_models.rs"));
-    include!(concat!(env!("OUT_DIR"), "/scenario.rs"));
-    include!(concat!(env!("OUT_DIR"), "/game_session.rs"));
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Unit {
-    pub id: Uuid,
-    pub name: String,
-    pub health: u32,
-    pub accuracy: f32,
-    pub sight_range: f32,
-    pub movement_speed: f32,
-    pub position: (f64, f64),
-    pub unit_type: String,
-    pub side: UnitSide,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum UnitSide {
-    Friendly,
-    Enemy,
-}
-
-#[derive(Clone, Serialize, Deserialize, Debug)]
-#[serde(tag = "type", content = "payload")]
-pub enum SocketMessageType {
-    Welcome,
-    Ping,
-    ChatMessage,
-    UnitUpdate,
-    GameCommand,
-    UnitListRequest,
-    UnitListResponse,
-    Disconnect,
-}
+    pub mod models {
+        include!(concat!(env!("OUT_DIR"), "/models.rs"));
+    }
+    pub mod auth {
+        include!(concat!(env!("OUT_DIR"), "/auth.rs"));
+    }
+    pub mod area_models {
+        include!(concat!(env!("OUT_DIR"), "/area_models.rs"));
+    }
+    pub mod scenario {
+        include!(concat!(env!("OUT_DIR"), "/scenario.rs"));
+    }
+    pub mod game_session {
+        include!(concat!(env!("OUT_DIR"), "/game_session.rs"));
+    }
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct SocketMessage {
-    pub msg_type: SocketMessageType,
-    pub payload: serde_json::Value,
+    pub use area_models::*;
+    pub use auth::*;
+    pub use game_session::*;
+    pub use models::*;
+    pub use scenario::*;
 }
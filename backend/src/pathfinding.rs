@@ -0,0 +1,399 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::game::spatial::AreaIndex;
+use crate::models::proto::{Position, ScenarioArea};
+use crate::utils::{first_blocked_cut, haversine_distance, point_in_area, segment_cuts};
+
+/// Cells along the longer side of the search grid.
+const GRID_CELLS: usize = 64;
+/// Extra room around the start/goal box, as a fraction of its longer side, so routes
+/// can bend around obstacles that sit between the two points.
+const GRID_MARGIN: f64 = 0.5;
+
+/// An area polygon and how fast units cross it; areas later in the list sit on top.
+/// A speed modifier of zero or less makes the area impassable.
+pub struct WeightedArea<'a> {
+    pub area: &'a ScenarioArea,
+    pub speed_modifier: f64,
+}
+
+/// Speed modifier of the topmost of `areas` at a point.
+fn speed_at<'a>(areas: impl DoubleEndedIterator<Item = &'a WeightedArea<'a>>, lat: f64, lon: f64) -> f64 {
+    areas
+        .rev()
+        .find(|weighted| point_in_area(lat, lon, weighted.area))
+        .map(|weighted| weighted.speed_modifier)
+        .unwrap_or(1.0)
+}
+
+/// Lat/lon box around an area.
+#[derive(Clone, Copy)]
+struct Bounds {
+    min_lat: f64,
+    min_lon: f64,
+    max_lat: f64,
+    max_lon: f64,
+}
+
+impl Bounds {
+    fn of(area: &ScenarioArea) -> Self {
+        area.coordinates.iter().flat_map(|ring| &ring.points).fold(
+            Bounds {
+                min_lat: f64::INFINITY,
+                min_lon: f64::INFINITY,
+                max_lat: f64::NEG_INFINITY,
+                max_lon: f64::NEG_INFINITY,
+            },
+            |b, p| Bounds {
+                min_lat: b.min_lat.min(p.lat),
+                min_lon: b.min_lon.min(p.lon),
+                max_lat: b.max_lat.max(p.lat),
+                max_lon: b.max_lon.max(p.lon),
+            },
+        )
+    }
+
+    fn touches_segment(&self, a: &Position, b: &Position) -> bool {
+        a.lat.max(b.lat) >= self.min_lat
+            && a.lat.min(b.lat) <= self.max_lat
+            && a.lon.max(b.lon) >= self.min_lon
+            && a.lon.min(b.lon) <= self.max_lon
+    }
+}
+
+/// Regular lat/lon grid covering the search region, with the speed modifier of each cell.
+struct Grid<'a> {
+    rows: usize,
+    cols: usize,
+    min_lat: f64,
+    min_lon: f64,
+    cell_lat: f64,
+    cell_lon: f64,
+    speed: Vec<f64>,
+    areas: &'a [WeightedArea<'a>],
+    bounds: Vec<Bounds>,
+}
+
+impl<'a> Grid<'a> {
+    fn new(start: &Position, goal: &Position, areas: &'a [WeightedArea<'a>], index: &AreaIndex) -> Self {
+        let span = (start.lat - goal.lat).abs().max((start.lon - goal.lon).abs()).max(1e-4);
+        let margin = span * GRID_MARGIN;
+        let min_lat = start.lat.min(goal.lat) - margin;
+        let min_lon = start.lon.min(goal.lon) - margin;
+        let height = (start.lat - goal.lat).abs() + 2.0 * margin;
+        let width = (start.lon - goal.lon).abs() + 2.0 * margin;
+
+        let cell = height.max(width) / GRID_CELLS as f64;
+        let rows = ((height / cell).ceil() as usize).max(1);
+        let cols = ((width / cell).ceil() as usize).max(1);
+
+        let mut grid = Self {
+            rows,
+            cols,
+            min_lat,
+            min_lon,
+            cell_lat: cell,
+            cell_lon: cell,
+            speed: Vec::with_capacity(rows * cols),
+            areas,
+            bounds: areas.iter().map(|weighted| Bounds::of(weighted.area)).collect(),
+        };
+
+        for cell in 0..rows * cols {
+            let center = grid.center(cell);
+            let candidates = index.candidates_at(center.lat, center.lon).iter().map(|&area| &areas[area]);
+            grid.speed.push(speed_at(candidates, center.lat, center.lon));
+        }
+        grid
+    }
+
+    fn cell_of(&self, position: &Position) -> usize {
+        let row = ((position.lat - self.min_lat) / self.cell_lat) as usize;
+        let col = ((position.lon - self.min_lon) / self.cell_lon) as usize;
+        row.min(self.rows - 1) * self.cols + col.min(self.cols - 1)
+    }
+
+    fn center(&self, index: usize) -> Position {
+        let (row, col) = (index / self.cols, index % self.cols);
+        Position {
+            lat: self.min_lat + (row as f64 + 0.5) * self.cell_lat,
+            lon: self.min_lon + (col as f64 + 0.5) * self.cell_lon,
+        }
+    }
+
+    fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (row, col) = ((index / self.cols) as isize, (index % self.cols) as isize);
+        (-1..=1)
+            .flat_map(move |dr| (-1..=1).map(move |dc| (row + dr, col + dc)))
+            .filter(move |&(r, c)| {
+                (r, c) != (row, col)
+                    && r >= 0
+                    && c >= 0
+                    && (r as usize) < self.rows
+                    && (c as usize) < self.cols
+            })
+            .map(move |(r, c)| r as usize * self.cols + c as usize)
+    }
+
+    fn passable(&self, index: usize) -> bool {
+        self.speed[index] > 0.0
+    }
+
+    /// Whether the straight leg from `a` to `b` enters an impassable area somewhere along
+    /// the way, not just at its ends. Impassable ground at `a` may be left.
+    fn leg_blocked(&self, a: &Position, b: &Position) -> bool {
+        let nearby: Vec<&WeightedArea> = self
+            .areas
+            .iter()
+            .zip(&self.bounds)
+            .filter(|(_, bounds)| bounds.touches_segment(a, b))
+            .map(|(weighted, _)| weighted)
+            .collect();
+        if nearby.iter().all(|weighted| weighted.speed_modifier > 0.0) {
+            return false;
+        }
+
+        let cuts = segment_cuts(a, b, nearby.iter().map(|weighted| weighted.area));
+        first_blocked_cut(a, b, &cuts, |lat, lon| speed_at(nearby.iter().copied(), lat, lon) <= 0.0).is_some()
+    }
+
+    /// Whether a unit can walk straight from one cell centre to an adjacent one.
+    fn can_move(&self, from: usize, to: usize) -> bool {
+        self.passable(to) && !self.leg_blocked(&self.center(from), &self.center(to))
+    }
+
+    /// Distance between two adjacent cells divided by their average speed modifier.
+    fn cost(&self, from: usize, to: usize) -> f64 {
+        let (a, b) = (self.center(from), self.center(to));
+        let speed = ((self.speed[from].max(0.0) + self.speed[to]) / 2.0).max(0.01);
+        haversine_distance(a.lat, a.lon, b.lat, b.lon) / speed
+    }
+}
+
+#[derive(PartialEq)]
+struct Candidate {
+    estimate: f64,
+    cell: usize,
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    // Reversed so the heap pops the lowest estimate first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* over a grid laid on top of the area polygons, minimising travel time rather than
+/// distance. Moves between cells never cut through an impassable area. Returns the
+/// waypoints after `start`, ending exactly at `goal`. `index` must be built over the same
+/// areas, in the same order.
+pub fn find_path(start: &Position, goal: &Position, areas: &[WeightedArea], index: &AreaIndex) -> Option<Vec<Position>> {
+    if ![start.lat, start.lon, goal.lat, goal.lon].iter().all(|v| v.is_finite()) {
+        return None;
+    }
+
+    let grid = Grid::new(start, goal, areas, index);
+    let (from, to) = (grid.cell_of(start), grid.cell_of(goal));
+
+    // Admissible heuristic: straight line at the fastest speed anywhere on the map
+    let fastest = areas
+        .iter()
+        .map(|weighted| weighted.speed_modifier)
+        .fold(1.0, f64::max);
+    let goal_center = grid.center(to);
+    let heuristic = |cell: usize| {
+        let center = grid.center(cell);
+        haversine_distance(center.lat, center.lon, goal_center.lat, goal_center.lon) / fastest
+    };
+
+    let cells = grid.rows * grid.cols;
+    let mut best = vec![f64::INFINITY; cells];
+    let mut came_from = vec![usize::MAX; cells];
+    let mut open = BinaryHeap::new();
+
+    best[from] = 0.0;
+    open.push(Candidate {
+        estimate: heuristic(from),
+        cell: from,
+    });
+
+    if !grid.passable(to) {
+        return None;
+    }
+
+    while let Some(Candidate { estimate, cell }) = open.pop() {
+        if cell == to {
+            break;
+        }
+        if estimate > best[cell] + heuristic(cell) {
+            continue;
+        }
+
+        // The start cell may overlap a blocked area; the unit is allowed to leave it
+        for next in grid.neighbours(cell).filter(|next| grid.can_move(cell, *next)) {
+            let cost = best[cell] + grid.cost(cell, next);
+            if cost < best[next] {
+                best[next] = cost;
+                came_from[next] = cell;
+                open.push(Candidate {
+                    estimate: cost + heuristic(next),
+                    cell: next,
+                });
+            }
+        }
+    }
+
+    if best[to].is_infinite() {
+        return None;
+    }
+
+    let mut cells_on_path = vec![to];
+    let mut cell = to;
+    while cell != from {
+        cell = came_from[cell];
+        cells_on_path.push(cell);
+    }
+    cells_on_path.reverse();
+
+    let mut waypoints = simplify(&grid, &cells_on_path, goal);
+
+    // The unit sets off from `start` rather than the centre of its cell, and the last leg
+    // ends off-grid at `goal`; either may still clip an obstacle inside those cells
+    if grid.leg_blocked(start, &waypoints[0]) {
+        waypoints.insert(0, grid.center(from));
+    }
+    let clear = std::iter::once(start)
+        .chain(&waypoints)
+        .zip(&waypoints)
+        .all(|(a, b)| !grid.leg_blocked(a, b));
+    clear.then_some(waypoints)
+}
+
+/// Keeps only the cells where the route changes direction, then ends at the exact goal.
+fn simplify(grid: &Grid, cells: &[usize], goal: &Position) -> Vec<Position> {
+    let step = |a: usize, b: usize| {
+        (
+            (b / grid.cols) as isize - (a / grid.cols) as isize,
+            (b % grid.cols) as isize - (a % grid.cols) as isize,
+        )
+    };
+
+    let mut waypoints: Vec<Position> = cells
+        .windows(3)
+        .filter(|w| step(w[0], w[1]) != step(w[1], w[2]))
+        .map(|w| grid.center(w[1]))
+        .collect();
+    waypoints.push(*goal);
+    waypoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{position, rectangle};
+    use crate::utils::segment_ring_crossings;
+
+    fn route(start: &Position, goal: &Position, areas: &[WeightedArea]) -> Option<Vec<Position>> {
+        let plain: Vec<ScenarioArea> = areas.iter().map(|weighted| weighted.area.clone()).collect();
+        find_path(start, goal, areas, &AreaIndex::build(&plain))
+    }
+
+    #[test]
+    fn open_ground_goes_straight_to_the_goal() {
+        let path = route(&position(50.0, 14.0), &position(50.01, 14.01), &[]).unwrap();
+        assert_eq!(path, vec![position(50.01, 14.01)]);
+    }
+
+    #[test]
+    fn routes_around_an_impassable_wall() {
+        let wall = rectangle(49.995, 14.004, 50.003, 14.006);
+        let areas = [WeightedArea {
+            area: &wall,
+            speed_modifier: 0.0,
+        }];
+        let (start, goal) = (position(50.0, 14.0), position(50.0, 14.01));
+
+        let path = route(&start, &goal, &areas).unwrap();
+
+        assert_eq!(path.last(), Some(&goal));
+        assert!(path.len() > 1);
+        assert!(path.iter().all(|p| !point_in_area(p.lat, p.lon, &wall)));
+    }
+
+    #[test]
+    fn never_cuts_through_a_wall_thinner_than_a_cell() {
+        let wall = rectangle(49.998, 14.005, 50.002, 14.00502);
+        let areas = [WeightedArea {
+            area: &wall,
+            speed_modifier: 0.0,
+        }];
+        let (start, goal) = (position(50.0, 14.0), position(50.0, 14.01));
+
+        let path = route(&start, &goal, &areas).unwrap();
+
+        let legs = std::iter::once(&start).chain(&path).zip(&path);
+        for (a, b) in legs {
+            assert!(segment_ring_crossings(a, b, &wall.coordinates[0].points).is_empty());
+        }
+    }
+
+    #[test]
+    fn bridge_over_a_river_is_passable() {
+        let river = rectangle(49.99, 14.004, 50.01, 14.006);
+        let bridge = rectangle(49.9995, 14.003, 50.0005, 14.007);
+        let areas = [
+            WeightedArea {
+                area: &river,
+                speed_modifier: 0.0,
+            },
+            WeightedArea {
+                area: &bridge,
+                speed_modifier: 1.0,
+            },
+        ];
+
+        assert!(route(&position(50.0, 14.0), &position(50.0, 14.01), &areas).is_some());
+    }
+
+    #[test]
+    fn blocked_goal_has_no_route() {
+        let lake = rectangle(49.99, 14.0, 50.01, 14.02);
+        let areas = [WeightedArea {
+            area: &lake,
+            speed_modifier: 0.0,
+        }];
+        assert!(route(&position(50.02, 13.98), &position(50.0, 14.01), &areas).is_none());
+    }
+
+    #[test]
+    fn start_equal_to_goal_is_a_single_waypoint() {
+        let here = position(50.0, 14.0);
+        assert_eq!(route(&here, &here, &[]), Some(vec![here]));
+    }
+
+    #[test]
+    fn non_finite_coordinates_have_no_route() {
+        let start = position(50.0, 14.0);
+        assert!(route(&start, &position(f64::NAN, 14.0), &[]).is_none());
+        assert!(route(&start, &position(50.0, f64::INFINITY), &[]).is_none());
+        assert!(route(&position(f64::NEG_INFINITY, 14.0), &start, &[]).is_none());
+    }
+
+    #[test]
+    fn grid_always_has_a_cell() {
+        let here = position(50.0, 14.0);
+        let grid = Grid::new(&here, &position(f64::NAN, f64::NAN), &[], &AreaIndex::default());
+        assert!(grid.rows >= 1 && grid.cols >= 1);
+        assert_eq!(grid.speed.len(), grid.rows * grid.cols);
+        assert_eq!(grid.cell_of(&here), 0);
+    }
+}
@@ -2,4 +2,5 @@ pub mod get_unit_types;
 pub mod get_area_types;
 pub mod create_scenario;
 pub mod get_scenario_by_id;
-pub mod get_scenarios;
\ No newline at end of file
+pub mod get_scenarios;
+pub mod auth_guest;
\ No newline at end of file
@@ -0,0 +1,41 @@
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use prost::Message;
+use redis::TypedCommands;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::AppState;
+use crate::auth::{TOKEN_TTL_SECS, token_key};
+use crate::models::proto::GuestAuthResponse;
+
+/// Issues a new guest identity. Clients keep the token and present it on every
+/// connection, so a page reload keeps the same `user_id`.
+pub async fn auth_guest(State(state): State<AppState>) -> impl IntoResponse {
+    let user_id = Uuid::new_v4().to_string();
+    let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    {
+        let mut redis = state.redis.lock().await;
+        if let Err(e) = redis.set_ex(token_key(&token), &user_id, TOKEN_TTL_SECS) {
+            error!("❌ Failed to store guest token: {}", e);
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Redis error").into_response();
+        }
+    }
+
+    let response = GuestAuthResponse {
+        token,
+        user_id: user_id.clone(),
+    };
+    let mut buf = Vec::new();
+    if response.encode(&mut buf).is_err() {
+        return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to encode protobuf").into_response();
+    }
+
+    info!("🪪 Issued guest identity {}", user_id);
+
+    let mut headers = HeaderMap::new();
+    headers.insert("Content-Type", "application/protobuf".parse().unwrap());
+    (headers, buf).into_response()
+}
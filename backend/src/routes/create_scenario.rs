@@ -4,7 +4,7 @@ use axum::extract::State;
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use mongodb::bson::to_bson;
-use tracing::{error, info};
+use tracing::error;
 use uuid::Uuid;
 
 use crate::AppState;
@@ -21,6 +21,20 @@ pub async fn list_area_types_protobuf() -> impl IntoResponse {
             movement_speed_modifier: a.movement_speed_modifier,
             accuracy_modifier: a.accuracy_modifier,
             enemy_miss_chance: a.enemy_miss_chance,
+            impassable: a.impassable,
+            impassable_for: a.impassable_for,
+            unit_overrides: a
+                .unit_overrides
+                .into_iter()
+                .map(|o| crate::models::proto::AreaUnitOverride {
+                    unit_class: o.unit_class,
+                    movement_speed_modifier: o.movement_speed_modifier,
+                    accuracy_modifier: o.accuracy_modifier,
+                    enemy_miss_chance: o.enemy_miss_chance,
+                })
+                .collect(),
+            line_of_sight_factor: a.line_of_sight_factor,
+            build_time: a.build_time,
         })
         .collect();
 
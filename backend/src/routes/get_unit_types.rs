@@ -1,9 +1,10 @@
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
+use crate::game::config::{RawCommandRadius, RawFireSupport};
 use crate::load_configs_from_file;
 use std::{path::Path};
 use prost::Message;
-use crate::models::proto::{UnitType, UnitTypeKey, UnitTypeList};
+use crate::models::proto::{CommandRadius, FireSupport, UnitType, UnitTypeKey, UnitTypeList};
 pub async fn get_unit_types() -> impl IntoResponse {
     let config_file = Path::new("../shared/configs/units-config.json");
 
@@ -27,6 +28,40 @@ pub async fn get_unit_types() -> impl IntoResponse {
                 sight_range: val.get("sight_range")?.as_f64()? as f32,
                 movement_speed: val.get("movement_speed")?.as_f64()? as f32,
                 damage: val.get("damage")?.as_u64()? as u32,
+                categories: val
+                    .get("categories")
+                    .and_then(|c| c.as_array())
+                    .map(|c| c.iter().filter_map(|v| Some(v.as_str()?.to_string())).collect())
+                    .unwrap_or_default(),
+                fire_support: val
+                    .get("fire_support")
+                    .and_then(|f| serde_json::from_value::<RawFireSupport>(f.clone()).ok())
+                    .map(|f| FireSupport {
+                        min_range: f.min_range,
+                        max_range: f.max_range,
+                        flight_time: f.flight_time,
+                        scatter: f.scatter,
+                        blast_radius: f.blast_radius,
+                        reload_time: f.reload_time,
+                    }),
+                builds: val
+                    .get("builds")
+                    .and_then(|b| b.as_array())
+                    .map(|b| b.iter().filter_map(|v| Some(v.as_str()?.to_string())).collect())
+                    .unwrap_or_default(),
+                command: val
+                    .get("command")
+                    .and_then(|c| serde_json::from_value::<RawCommandRadius>(c.clone()).ok())
+                    .map(|c| CommandRadius {
+                        radius: c.radius,
+                        accuracy_modifier: c.accuracy_modifier,
+                        speed_modifier: c.speed_modifier,
+                        order_delay: c.order_delay,
+                        loss_accuracy_modifier: c.loss_accuracy_modifier,
+                        loss_speed_modifier: c.loss_speed_modifier,
+                    }),
+                cost: val.get("cost").and_then(|c| c.as_u64()).map(|c| c as u32),
+                build_time: val.get("build_time").and_then(|b| b.as_f64()).unwrap_or_default() as f32,
             })
         })
         .collect();
@@ -0,0 +1,23 @@
+//! Fixtures shared by the unit tests.
+
+use crate::models::proto::{Position, Ring, ScenarioArea};
+
+pub fn position(lat: f64, lon: f64) -> Position {
+    Position { lat, lon }
+}
+
+/// Area with a single rectangular ring.
+pub fn rectangle(min_lat: f64, min_lon: f64, max_lat: f64, max_lon: f64) -> ScenarioArea {
+    ScenarioArea {
+        id: None,
+        r#type: "Test".to_string(),
+        coordinates: vec![Ring {
+            points: vec![
+                position(min_lat, min_lon),
+                position(min_lat, max_lon),
+                position(max_lat, max_lon),
+                position(max_lat, min_lon),
+            ],
+        }],
+    }
+}
@@ -1,19 +1,7 @@
-use axum::http::{HeaderMap, StatusCode};
-use axum::response::IntoResponse;
-use mongodb::bson::{doc, Document};
-use mongodb::{bson, Database};
-use prost::Message;
+use crate::models::proto::{Position, ScenarioArea};
 
-pub fn protobuf_response<T: Message>(message: &T) -> Result<impl IntoResponse, (StatusCode, String)> {
-    let mut buffer = Vec::new();
-    message.encode(&mut buffer).map_err(|_| {
-        (StatusCode::INTERNAL_SERVER_ERROR, "Protobuf encoding failed".to_string())
-    })?;
-
-    let mut headers = HeaderMap::new();
-    headers.insert("Content-Type", "application/protobuf".parse().unwrap());
-    Ok((headers, buffer))
-}
+/// Length of one degree of latitude, in km.
+pub const KM_PER_DEG_LAT: f64 = 111.32;
 
 pub fn interpolate(start: f64, end: f64, t: f64) -> f64 {
     start + (end - start) * t
@@ -33,29 +21,171 @@ pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     r * c
 }
 
-pub async fn get_unit_position_from_mongo(
-    db: &Database,
-    unit_id: &str,
-) -> Option<(f64, f64)> {
-    let scenario_doc = db
-        .collection::<Document>("scenarios")
-        .find_one(doc! { "UNITS.ID": unit_id })
-        .await
-        .ok()
-        .flatten()?;
-
-    let units = scenario_doc.get_array("UNITS").ok()?;
-
-    for unit in units {
-        if let bson::Bson::Document(unit_doc) = unit {
-            if unit_doc.get_str("ID").ok()? == unit_id {
-                let pos = unit_doc.get_document("POSITION").ok()?;
-                let lat = pos.get_f64("LAT").ok()?;
-                let lon = pos.get_f64("LON").ok()?;
-                return Some((lat, lon));
+/// Moves a point by a small distance north and east, in km.
+pub fn offset_position(position: &Position, north_km: f64, east_km: f64) -> Position {
+    let km_per_deg_lon = KM_PER_DEG_LAT * position.lat.to_radians().cos().max(0.01);
+    Position {
+        lat: position.lat + north_km / KM_PER_DEG_LAT,
+        lon: position.lon + east_km / km_per_deg_lon,
+    }
+}
+
+/// Even-odd test of a point against a ring treated as a flat lon/lat polygon.
+pub fn point_in_ring(lat: f64, lon: f64, ring: &[Position]) -> bool {
+    let mut inside = false;
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let (a, b) = (&ring[i], &ring[j]);
+        if (a.lat > lat) != (b.lat > lat)
+            && lon < (b.lon - a.lon) * (lat - a.lat) / (b.lat - a.lat) + a.lon
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// A point is inside an area when it falls inside an odd number of its rings,
+/// so inner rings act as holes and disjoint rings as separate parts.
+pub fn point_in_area(lat: f64, lon: f64, area: &ScenarioArea) -> bool {
+    area.coordinates
+        .iter()
+        .filter(|ring| point_in_ring(lat, lon, &ring.points))
+        .count()
+        % 2
+        == 1
+}
+
+/// Where the segment from `a` to `b` crosses the edges of a ring, as fractions of the
+/// way along the segment. Like `point_in_ring`, coordinates are treated as flat lon/lat.
+pub fn segment_ring_crossings(a: &Position, b: &Position, ring: &[Position]) -> Vec<f64> {
+    let (dx, dy) = (b.lon - a.lon, b.lat - a.lat);
+    let mut crossings = Vec::new();
+    let mut j = ring.len().wrapping_sub(1);
+    for i in 0..ring.len() {
+        let (p, q) = (&ring[j], &ring[i]);
+        let (ex, ey) = (q.lon - p.lon, q.lat - p.lat);
+        let denom = dx * ey - dy * ex;
+        // Parallel edges never cross the segment at a single point
+        if denom != 0.0 {
+            let (wx, wy) = (p.lon - a.lon, p.lat - a.lat);
+            let t = (wx * ey - wy * ex) / denom;
+            let u = (wx * dy - wy * dx) / denom;
+            if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+                crossings.push(t);
             }
         }
+        j = i;
     }
+    crossings
+}
+
+/// Where the segment from `a` to `b` crosses an edge of any of `areas`, as sorted
+/// fractions of the way along it with both ends included. Between two consecutive
+/// cuts the segment stays inside the same areas.
+pub fn segment_cuts<'a>(a: &Position, b: &Position, areas: impl IntoIterator<Item = &'a ScenarioArea>) -> Vec<f64> {
+    let mut cuts: Vec<f64> = areas
+        .into_iter()
+        .flat_map(|area| &area.coordinates)
+        .flat_map(|ring| segment_ring_crossings(a, b, &ring.points))
+        .collect();
+    cuts.extend([0.0, 1.0]);
+    cuts.sort_by(f64::total_cmp);
+    cuts
+}
 
+/// The cut at which the segment from `a` to `b` first enters ground `blocked` reports
+/// closed. Closed ground the segment starts in does not count, so it may be left.
+pub fn first_blocked_cut(a: &Position, b: &Position, cuts: &[f64], blocked: impl Fn(f64, f64) -> bool) -> Option<f64> {
+    let mut left_start = false;
+    for stretch in cuts.windows(2) {
+        let mid = (stretch[0] + stretch[1]) / 2.0;
+        if blocked(interpolate(a.lat, b.lat, mid), interpolate(a.lon, b.lon, mid)) {
+            if left_start {
+                return Some(stretch[0]);
+            }
+        } else {
+            left_start = true;
+        }
+    }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::proto::Ring;
+    use crate::test_support::position;
+
+    fn square(min: f64, max: f64) -> Vec<Position> {
+        vec![position(min, min), position(min, max), position(max, max), position(max, min)]
+    }
+
+    #[test]
+    fn point_in_ring_is_even_odd() {
+        let ring = square(0.0, 1.0);
+        assert!(point_in_ring(0.5, 0.5, &ring));
+        assert!(!point_in_ring(1.5, 0.5, &ring));
+        assert!(!point_in_ring(0.5, -0.5, &ring));
+    }
+
+    #[test]
+    fn inner_ring_is_a_hole() {
+        let area = ScenarioArea {
+            id: None,
+            r#type: "Test".to_string(),
+            coordinates: vec![Ring { points: square(0.0, 3.0) }, Ring { points: square(1.0, 2.0) }],
+        };
+        assert!(point_in_area(0.5, 0.5, &area));
+        assert!(!point_in_area(1.5, 1.5, &area));
+        assert!(!point_in_area(4.0, 4.0, &area));
+    }
+
+    #[test]
+    fn segment_crosses_both_sides_of_a_ring() {
+        let ring = square(0.0, 1.0);
+        let mut crossings = segment_ring_crossings(&position(0.5, -1.0), &position(0.5, 2.0), &ring);
+        crossings.sort_by(f64::total_cmp);
+
+        assert_eq!(crossings.len(), 2);
+        assert!((crossings[0] - 1.0 / 3.0).abs() < 1e-9);
+        assert!((crossings[1] - 2.0 / 3.0).abs() < 1e-9);
+        assert!(segment_ring_crossings(&position(2.0, 2.0), &position(3.0, 3.0), &ring).is_empty());
+    }
+
+    #[test]
+    fn first_blocked_cut_stops_at_the_entry() {
+        let wall = square(0.4, 0.6);
+        let blocked = |lat: f64, lon: f64| point_in_ring(lat, lon, &wall);
+        let (a, b) = (position(0.5, 0.0), position(0.5, 1.0));
+        let mut cuts = segment_ring_crossings(&a, &b, &wall);
+        cuts.extend([0.0, 1.0]);
+        cuts.sort_by(f64::total_cmp);
+
+        let cut = first_blocked_cut(&a, &b, &cuts, blocked).unwrap();
+        assert!((cut - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn first_blocked_cut_lets_units_leave_closed_ground() {
+        let wall = square(0.4, 0.6);
+        let blocked = |lat: f64, lon: f64| point_in_ring(lat, lon, &wall);
+        let (a, b) = (position(0.5, 0.5), position(0.5, 1.0));
+        let mut cuts = segment_ring_crossings(&a, &b, &wall);
+        cuts.extend([0.0, 1.0]);
+        cuts.sort_by(f64::total_cmp);
+
+        assert_eq!(first_blocked_cut(&a, &b, &cuts, blocked), None);
+    }
+
+    #[test]
+    fn offset_position_moves_by_kilometres() {
+        let origin = position(50.0, 14.0);
+        let moved = offset_position(&origin, 1.0, 1.0);
+        let north = haversine_distance(origin.lat, origin.lon, moved.lat, origin.lon);
+        let east = haversine_distance(origin.lat, origin.lon, origin.lat, moved.lon);
+        assert!((north - 1.0).abs() < 0.01);
+        assert!((east - 1.0).abs() < 0.01);
+    }
+}
@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-ts_proto. DO NOT EDIT.
 // versions:
 //   protoc-gen-ts_proto  v2.7.3
-//   protoc               v3.20.3
+//   protoc               v7.36.2
 // source: area_types.proto
 
 /* eslint-disable */
@@ -19,6 +19,26 @@ export interface Area {
   accuracyModifier: number;
   /** e.g., 0.2 means 20% chance to miss */
   enemyMissChance: number;
+  /** blocks every unit */
+  impassable: boolean;
+  /** unit type keys or categories it blocks, e.g., "vehicle" */
+  impassableFor: string[];
+  unitOverrides: AreaUnitOverride[];
+  /** e.g., 4.0 means 100 m inside counts as 400 m of sight */
+  lineOfSightFactor: number;
+  /** seconds; only set for areas engineers can build */
+  buildTime?: number | undefined;
+}
+
+/**
+ * Replaces the area's modifiers for one unit type key (e.g., "HEAVY_ARMOUR") or
+ * category (e.g., "infantry"). A type key override wins over a category override.
+ */
+export interface AreaUnitOverride {
+  unitClass: string;
+  movementSpeedModifier?: number | undefined;
+  accuracyModifier?: number | undefined;
+  enemyMissChance?: number | undefined;
 }
 
 export interface AreaList {
@@ -26,7 +46,19 @@ export interface AreaList {
 }
 
 function createBaseArea(): Area {
-  return { name: "", description: "", color: "", movementSpeedModifier: 0, accuracyModifier: 0, enemyMissChance: 0 };
+  return {
+    name: "",
+    description: "",
+    color: "",
+    movementSpeedModifier: 0,
+    accuracyModifier: 0,
+    enemyMissChance: 0,
+    impassable: false,
+    impassableFor: [],
+    unitOverrides: [],
+    lineOfSightFactor: 0,
+    buildTime: undefined,
+  };
 }
 
 export const Area: MessageFns<Area> = {
@@ -49,6 +81,21 @@ export const Area: MessageFns<Area> = {
     if (message.enemyMissChance !== 0) {
       writer.uint32(53).float(message.enemyMissChance);
     }
+    if (message.impassable !== false) {
+      writer.uint32(56).bool(message.impassable);
+    }
+    for (const v of message.impassableFor) {
+      writer.uint32(66).string(v!);
+    }
+    for (const v of message.unitOverrides) {
+      AreaUnitOverride.encode(v!, writer.uint32(74).fork()).join();
+    }
+    if (message.lineOfSightFactor !== 0) {
+      writer.uint32(85).float(message.lineOfSightFactor);
+    }
+    if (message.buildTime !== undefined) {
+      writer.uint32(93).float(message.buildTime);
+    }
     return writer;
   },
 
@@ -107,6 +154,46 @@ export const Area: MessageFns<Area> = {
           message.enemyMissChance = reader.float();
           continue;
         }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.impassable = reader.bool();
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.impassableFor.push(reader.string());
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.unitOverrides.push(AreaUnitOverride.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 10: {
+          if (tag !== 85) {
+            break;
+          }
+
+          message.lineOfSightFactor = reader.float();
+          continue;
+        }
+        case 11: {
+          if (tag !== 93) {
+            break;
+          }
+
+          message.buildTime = reader.float();
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -124,6 +211,15 @@ export const Area: MessageFns<Area> = {
       movementSpeedModifier: isSet(object.movementSpeedModifier) ? globalThis.Number(object.movementSpeedModifier) : 0,
       accuracyModifier: isSet(object.accuracyModifier) ? globalThis.Number(object.accuracyModifier) : 0,
       enemyMissChance: isSet(object.enemyMissChance) ? globalThis.Number(object.enemyMissChance) : 0,
+      impassable: isSet(object.impassable) ? globalThis.Boolean(object.impassable) : false,
+      impassableFor: globalThis.Array.isArray(object?.impassableFor)
+        ? object.impassableFor.map((e: any) => globalThis.String(e))
+        : [],
+      unitOverrides: globalThis.Array.isArray(object?.unitOverrides)
+        ? object.unitOverrides.map((e: any) => AreaUnitOverride.fromJSON(e))
+        : [],
+      lineOfSightFactor: isSet(object.lineOfSightFactor) ? globalThis.Number(object.lineOfSightFactor) : 0,
+      buildTime: isSet(object.buildTime) ? globalThis.Number(object.buildTime) : undefined,
     };
   },
 
@@ -147,6 +243,21 @@ export const Area: MessageFns<Area> = {
     if (message.enemyMissChance !== 0) {
       obj.enemyMissChance = message.enemyMissChance;
     }
+    if (message.impassable !== false) {
+      obj.impassable = message.impassable;
+    }
+    if (message.impassableFor?.length) {
+      obj.impassableFor = message.impassableFor;
+    }
+    if (message.unitOverrides?.length) {
+      obj.unitOverrides = message.unitOverrides.map((e) => AreaUnitOverride.toJSON(e));
+    }
+    if (message.lineOfSightFactor !== 0) {
+      obj.lineOfSightFactor = message.lineOfSightFactor;
+    }
+    if (message.buildTime !== undefined) {
+      obj.buildTime = message.buildTime;
+    }
     return obj;
   },
 
@@ -161,6 +272,121 @@ export const Area: MessageFns<Area> = {
     message.movementSpeedModifier = object.movementSpeedModifier ?? 0;
     message.accuracyModifier = object.accuracyModifier ?? 0;
     message.enemyMissChance = object.enemyMissChance ?? 0;
+    message.impassable = object.impassable ?? false;
+    message.impassableFor = object.impassableFor?.map((e) => e) || [];
+    message.unitOverrides = object.unitOverrides?.map((e) => AreaUnitOverride.fromPartial(e)) || [];
+    message.lineOfSightFactor = object.lineOfSightFactor ?? 0;
+    message.buildTime = object.buildTime ?? undefined;
+    return message;
+  },
+};
+
+function createBaseAreaUnitOverride(): AreaUnitOverride {
+  return { unitClass: "", movementSpeedModifier: undefined, accuracyModifier: undefined, enemyMissChance: undefined };
+}
+
+export const AreaUnitOverride: MessageFns<AreaUnitOverride> = {
+  encode(message: AreaUnitOverride, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.unitClass !== "") {
+      writer.uint32(10).string(message.unitClass);
+    }
+    if (message.movementSpeedModifier !== undefined) {
+      writer.uint32(21).float(message.movementSpeedModifier);
+    }
+    if (message.accuracyModifier !== undefined) {
+      writer.uint32(29).float(message.accuracyModifier);
+    }
+    if (message.enemyMissChance !== undefined) {
+      writer.uint32(37).float(message.enemyMissChance);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): AreaUnitOverride {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAreaUnitOverride();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.unitClass = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 21) {
+            break;
+          }
+
+          message.movementSpeedModifier = reader.float();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.accuracyModifier = reader.float();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.enemyMissChance = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AreaUnitOverride {
+    return {
+      unitClass: isSet(object.unitClass) ? globalThis.String(object.unitClass) : "",
+      movementSpeedModifier: isSet(object.movementSpeedModifier)
+        ? globalThis.Number(object.movementSpeedModifier)
+        : undefined,
+      accuracyModifier: isSet(object.accuracyModifier) ? globalThis.Number(object.accuracyModifier) : undefined,
+      enemyMissChance: isSet(object.enemyMissChance) ? globalThis.Number(object.enemyMissChance) : undefined,
+    };
+  },
+
+  toJSON(message: AreaUnitOverride): unknown {
+    const obj: any = {};
+    if (message.unitClass !== "") {
+      obj.unitClass = message.unitClass;
+    }
+    if (message.movementSpeedModifier !== undefined) {
+      obj.movementSpeedModifier = message.movementSpeedModifier;
+    }
+    if (message.accuracyModifier !== undefined) {
+      obj.accuracyModifier = message.accuracyModifier;
+    }
+    if (message.enemyMissChance !== undefined) {
+      obj.enemyMissChance = message.enemyMissChance;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AreaUnitOverride>, I>>(base?: I): AreaUnitOverride {
+    return AreaUnitOverride.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AreaUnitOverride>, I>>(object: I): AreaUnitOverride {
+    const message = createBaseAreaUnitOverride();
+    message.unitClass = object.unitClass ?? "";
+    message.movementSpeedModifier = object.movementSpeedModifier ?? undefined;
+    message.accuracyModifier = object.accuracyModifier ?? undefined;
+    message.enemyMissChance = object.enemyMissChance ?? undefined;
     return message;
   },
 };
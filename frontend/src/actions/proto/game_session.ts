@@ -1,14 +1,187 @@
 // Code generated by protoc-gen-ts_proto. DO NOT EDIT.
 // versions:
 //   protoc-gen-ts_proto  v2.7.3
-//   protoc               v3.20.3
+//   protoc               v7.36.2
 // source: game_session.proto
 
 /* eslint-disable */
 import { BinaryReader, BinaryWriter } from "@bufbuild/protobuf/wire";
+import {
+  Objective,
+  ObjectiveState,
+  objectiveStateFromJSON,
+  objectiveStateToJSON,
+  Position,
+  Ring,
+  ScenarioArea,
+  SpawnPoint,
+  UnitSide,
+  unitSideFromJSON,
+  unitSideToJSON,
+} from "./scenario";
 
 export const protobufPackage = "game_session";
 
+export enum CommandRejectionReason {
+  REJECTION_REASON_UNSPECIFIED = 0,
+  NOT_IN_SESSION = 1,
+  GAME_NOT_STARTED = 2,
+  UNIT_NOT_FOUND = 3,
+  NOT_UNIT_OWNER = 4,
+  INVALID_ORDER = 5,
+  TERRAIN_IMPASSABLE = 6,
+  NO_ROUTE = 7,
+  OUT_OF_RANGE = 8,
+  TARGET_NOT_SPOTTED = 9,
+  UNIT_RELOADING = 10,
+  CANNOT_BUILD = 11,
+  INSUFFICIENT_SUPPLIES = 12,
+  UNIT_ROUTING = 13,
+  UNRECOGNIZED = -1,
+}
+
+export function commandRejectionReasonFromJSON(object: any): CommandRejectionReason {
+  switch (object) {
+    case 0:
+    case "REJECTION_REASON_UNSPECIFIED":
+      return CommandRejectionReason.REJECTION_REASON_UNSPECIFIED;
+    case 1:
+    case "NOT_IN_SESSION":
+      return CommandRejectionReason.NOT_IN_SESSION;
+    case 2:
+    case "GAME_NOT_STARTED":
+      return CommandRejectionReason.GAME_NOT_STARTED;
+    case 3:
+    case "UNIT_NOT_FOUND":
+      return CommandRejectionReason.UNIT_NOT_FOUND;
+    case 4:
+    case "NOT_UNIT_OWNER":
+      return CommandRejectionReason.NOT_UNIT_OWNER;
+    case 5:
+    case "INVALID_ORDER":
+      return CommandRejectionReason.INVALID_ORDER;
+    case 6:
+    case "TERRAIN_IMPASSABLE":
+      return CommandRejectionReason.TERRAIN_IMPASSABLE;
+    case 7:
+    case "NO_ROUTE":
+      return CommandRejectionReason.NO_ROUTE;
+    case 8:
+    case "OUT_OF_RANGE":
+      return CommandRejectionReason.OUT_OF_RANGE;
+    case 9:
+    case "TARGET_NOT_SPOTTED":
+      return CommandRejectionReason.TARGET_NOT_SPOTTED;
+    case 10:
+    case "UNIT_RELOADING":
+      return CommandRejectionReason.UNIT_RELOADING;
+    case 11:
+    case "CANNOT_BUILD":
+      return CommandRejectionReason.CANNOT_BUILD;
+    case 12:
+    case "INSUFFICIENT_SUPPLIES":
+      return CommandRejectionReason.INSUFFICIENT_SUPPLIES;
+    case 13:
+    case "UNIT_ROUTING":
+      return CommandRejectionReason.UNIT_ROUTING;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return CommandRejectionReason.UNRECOGNIZED;
+  }
+}
+
+export function commandRejectionReasonToJSON(object: CommandRejectionReason): string {
+  switch (object) {
+    case CommandRejectionReason.REJECTION_REASON_UNSPECIFIED:
+      return "REJECTION_REASON_UNSPECIFIED";
+    case CommandRejectionReason.NOT_IN_SESSION:
+      return "NOT_IN_SESSION";
+    case CommandRejectionReason.GAME_NOT_STARTED:
+      return "GAME_NOT_STARTED";
+    case CommandRejectionReason.UNIT_NOT_FOUND:
+      return "UNIT_NOT_FOUND";
+    case CommandRejectionReason.NOT_UNIT_OWNER:
+      return "NOT_UNIT_OWNER";
+    case CommandRejectionReason.INVALID_ORDER:
+      return "INVALID_ORDER";
+    case CommandRejectionReason.TERRAIN_IMPASSABLE:
+      return "TERRAIN_IMPASSABLE";
+    case CommandRejectionReason.NO_ROUTE:
+      return "NO_ROUTE";
+    case CommandRejectionReason.OUT_OF_RANGE:
+      return "OUT_OF_RANGE";
+    case CommandRejectionReason.TARGET_NOT_SPOTTED:
+      return "TARGET_NOT_SPOTTED";
+    case CommandRejectionReason.UNIT_RELOADING:
+      return "UNIT_RELOADING";
+    case CommandRejectionReason.CANNOT_BUILD:
+      return "CANNOT_BUILD";
+    case CommandRejectionReason.INSUFFICIENT_SUPPLIES:
+      return "INSUFFICIENT_SUPPLIES";
+    case CommandRejectionReason.UNIT_ROUTING:
+      return "UNIT_ROUTING";
+    case CommandRejectionReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export enum OrderCancelReason {
+  ORDER_CANCEL_REASON_UNSPECIFIED = 0,
+  /** a new order replaced it */
+  SUPERSEDED = 1,
+  /** the player stopped the unit */
+  STOPPED = 2,
+  /** the path ran into impassable terrain */
+  BLOCKED = 3,
+  /** the unit broke and is retreating on its own */
+  ROUTED = 4,
+  UNRECOGNIZED = -1,
+}
+
+export function orderCancelReasonFromJSON(object: any): OrderCancelReason {
+  switch (object) {
+    case 0:
+    case "ORDER_CANCEL_REASON_UNSPECIFIED":
+      return OrderCancelReason.ORDER_CANCEL_REASON_UNSPECIFIED;
+    case 1:
+    case "SUPERSEDED":
+      return OrderCancelReason.SUPERSEDED;
+    case 2:
+    case "STOPPED":
+      return OrderCancelReason.STOPPED;
+    case 3:
+    case "BLOCKED":
+      return OrderCancelReason.BLOCKED;
+    case 4:
+    case "ROUTED":
+      return OrderCancelReason.ROUTED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return OrderCancelReason.UNRECOGNIZED;
+  }
+}
+
+export function orderCancelReasonToJSON(object: OrderCancelReason): string {
+  switch (object) {
+    case OrderCancelReason.ORDER_CANCEL_REASON_UNSPECIFIED:
+      return "ORDER_CANCEL_REASON_UNSPECIFIED";
+    case OrderCancelReason.SUPERSEDED:
+      return "SUPERSEDED";
+    case OrderCancelReason.STOPPED:
+      return "STOPPED";
+    case OrderCancelReason.BLOCKED:
+      return "BLOCKED";
+    case OrderCancelReason.ROUTED:
+      return "ROUTED";
+    case OrderCancelReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 /** Request to start a session */
 export interface StartSessionRequest {
   userId: string;
@@ -55,6 +228,13 @@ export interface SessionReadyEvent {
 export interface WsClientMessage {
   ping?: string | undefined;
   moveUnit?: MoveUnitRequest | undefined;
+  surrender?: SurrenderRequest | undefined;
+  stopUnit?: StopUnitRequest | undefined;
+  moveUnitPath?: MoveUnitPathRequest | undefined;
+  fireMission?: FireMissionRequest | undefined;
+  build?: BuildRequest | undefined;
+  buildUnit?: BuildUnitRequest | undefined;
+  requestSnapshot?: RequestSnapshot | undefined;
 }
 
 /** Sent when the creator starts the game */
@@ -69,11 +249,43 @@ export interface GameEndedEvent {
   reason: string;
 }
 
+/**
+ * Frames of a running game carry the tick they were produced in and a sequence number
+ * counting up by one for every frame sent to the player. A gap in the sequence means
+ * frames were lost and the client should send RequestSnapshot. Every GameSnapshot,
+ * including the one sent on reconnect, restarts the sequence at 1. Frames from outside
+ * the game loop, such as session_ready or game_started, have sequence 0 and are not
+ * counted. `game_time_secs` is the server's game clock, which all movement timestamps
+ * refer to.
+ */
 export interface WsServerMessage {
+  sequence: string;
+  tick: string;
+  gameTimeSecs: number;
   sessionReady?: SessionReadyEvent | undefined;
   gameStarted?: GameStartedEvent | undefined;
   gameEnded?: GameEndedEvent | undefined;
-  unitMoved?: MoveUnitBroadcast | undefined;
+  gameState?: GameStateEvent | undefined;
+  unitDestroyed?: UnitDestroyedEvent | undefined;
+  unitSpotted?: UnitSpottedEvent | undefined;
+  unitLost?: UnitLostEvent | undefined;
+  objectiveStateChanged?: ObjectiveStateChangedEvent | undefined;
+  commandRejected?: CommandRejectedEvent | undefined;
+  unitOrderCancelled?: UnitOrderCancelledEvent | undefined;
+  unitPathUpdated?: UnitPathUpdatedEvent | undefined;
+  fireMissionLaunched?: FireMissionLaunchedEvent | undefined;
+  shellImpact?: ShellImpactEvent | undefined;
+  constructionStarted?: ConstructionStartedEvent | undefined;
+  constructionAbandoned?: ConstructionAbandonedEvent | undefined;
+  areaCreated?: AreaCreatedEvent | undefined;
+  headquartersLost?: HeadquartersLostEvent | undefined;
+  resources?: ResourcesUpdate | undefined;
+  unitSpawned?: UnitSpawnedEvent | undefined;
+  playerDisconnected?: PlayerDisconnectedEvent | undefined;
+  playerReconnected?: PlayerReconnectedEvent | undefined;
+  gameSnapshot?: GameSnapshot | undefined;
+  tickUpdate?: TickUpdate | undefined;
+  unitMovementStarted?: UnitMovementStarted | undefined;
 }
 
 export interface MoveUnitRequest {
@@ -81,15 +293,301 @@ export interface MoveUnitRequest {
   unitId: string;
   targetLat: number;
   targetLon: number;
+  /** route around slow terrain; the route comes back as UnitPathUpdatedEvent */
+  fastestRoute: boolean;
+}
+
+/** Moves a unit through waypoints in order */
+export interface MoveUnitPathRequest {
+  sessionId: string;
+  unitId: string;
+  waypoints: Position[];
+  /** queue after the current path instead of replacing it */
+  append: boolean;
 }
 
-export interface MoveUnitBroadcast {
+export interface StopUnitRequest {
+  sessionId: string;
+  unitId: string;
+}
+
+/** Orders an artillery or mortar unit to shell a map position its side can see */
+export interface FireMissionRequest {
   sessionId: string;
   unitId: string;
   targetLat: number;
   targetLon: number;
 }
 
+/**
+ * Orders an engineer unit to build an area, e.g., a fortification or a bridge, on the
+ * given outline. The unit has to stay put until the build time is up.
+ */
+export interface BuildRequest {
+  sessionId: string;
+  unitId: string;
+  /** area name from areas-config, e.g., "Bridge" */
+  areaType: string;
+  outline: Ring | undefined;
+}
+
+/** Spends supplies to produce a unit at a friendly headquarters or spawn point */
+export interface BuildUnitRequest {
+  sessionId: string;
+  /** type key from units-config, e.g., "INFANTRY" */
+  unitType: string;
+  /** id of a friendly headquarters unit or spawn point */
+  spawnId: string;
+}
+
+/** Asks for a GameSnapshot, e.g., after joining late, reloading or missing messages */
+export interface RequestSnapshot {
+  sessionId: string;
+}
+
+export interface SurrenderRequest {
+  sessionId: string;
+}
+
+/** Live state of a unit inside a running session */
+export interface LiveUnit {
+  unitId: string;
+  position: Position | undefined;
+  unitKey: string;
+  side: UnitSide;
+  icon: string;
+  health: number;
+  maxHealth: number;
+  /** 0..1; the unit routs at 0 */
+  morale: number;
+  /** 0..1; lowers accuracy and speed */
+  suppression: number;
+  /** retreating on its own and ignoring orders */
+  routing: boolean;
+}
+
+/** Full game state, sent to both players once the scenario is loaded */
+export interface GameStateEvent {
+  sessionId: string;
+  units: LiveUnit[];
+  objectives: Objective[];
+  areas: ScenarioArea[];
+  /** the receiving side's own */
+  spawnPoints: SpawnPoint[];
+}
+
+/** A unit lost all of its health and was removed from the game */
+export interface UnitDestroyedEvent {
+  sessionId: string;
+  unitId: string;
+  /** empty unless the player can see the attacker */
+  attackerId: string;
+}
+
+/** An enemy unit came into sight of one of the player's units */
+export interface UnitSpottedEvent {
+  sessionId: string;
+  unit: LiveUnit | undefined;
+}
+
+/** An enemy unit left the sight range of all the player's units */
+export interface UnitLostEvent {
+  sessionId: string;
+  unitId: string;
+}
+
+/** Capture progress or ownership of an objective changed */
+export interface ObjectiveStateChangedEvent {
+  sessionId: string;
+  objectiveId: string;
+  letter: string;
+  state: ObjectiveState;
+  /** 0..1, stays at 1 while the owner holds it */
+  progress: number;
+  owner?: UnitSide | undefined;
+  capturingSide?: UnitSide | undefined;
+}
+
+/** Sent only to the player whose command the server refused */
+export interface CommandRejectedEvent {
+  sessionId: string;
+  /** e.g., "move_unit" */
+  command: string;
+  reason: CommandRejectionReason;
+  message: string;
+}
+
+/** Sent to the unit's owner when its current order ends before completion */
+export interface UnitOrderCancelledEvent {
+  sessionId: string;
+  unitId: string;
+  reason: OrderCancelReason;
+  /** where the unit halted or continues from */
+  position: Position | undefined;
+}
+
+/** Sent to the unit's owner whenever the path ahead of a unit changes; empty once it arrives */
+export interface UnitPathUpdatedEvent {
+  sessionId: string;
+  unitId: string;
+  remaining: Position[];
+  /** order delay left before the unit sets off */
+  startsInSecs: number;
+}
+
+/** Sent to the firing side when a fire mission is accepted */
+export interface FireMissionLaunchedEvent {
+  sessionId: string;
+  unitId: string;
+  target: Position | undefined;
+  impactInSecs: number;
+}
+
+/** A shell landed; every unit inside the blast radius reports its own damage */
+export interface ShellImpactEvent {
+  sessionId: string;
+  /** the unit that fired; empty unless the player can see it */
+  unitId: string;
+  position: Position | undefined;
+  /** metres */
+  blastRadius: number;
+}
+
+/** Sent to the builder's side when an engineer starts building */
+export interface ConstructionStartedEvent {
+  sessionId: string;
+  unitId: string;
+  areaType: string;
+  completeInSecs: number;
+}
+
+/** Sent to the builder's side when the engineer moved off or was destroyed before finishing */
+export interface ConstructionAbandonedEvent {
+  sessionId: string;
+  unitId: string;
+  areaType: string;
+}
+
+/** A new area became part of the map and of every terrain lookup */
+export interface AreaCreatedEvent {
+  sessionId: string;
+  area: ScenarioArea | undefined;
+  /** unit id; empty unless the player can see the builder */
+  builtBy: string;
+}
+
+/**
+ * A side lost its last headquarters; its units now fight and move worse and react
+ * to orders more slowly
+ */
+export interface HeadquartersLostEvent {
+  sessionId: string;
+  side: UnitSide;
+}
+
+/** A unit waiting to be produced */
+export interface UnitInProduction {
+  unitType: string;
+  completeInSecs: number;
+}
+
+/** Sent to each player periodically and whenever they order a unit */
+export interface ResourcesUpdate {
+  sessionId: string;
+  supplies: number;
+  unitsInBuild: number;
+  production: UnitInProduction[];
+}
+
+/** A produced unit joined the player's side */
+export interface UnitSpawnedEvent {
+  sessionId: string;
+  unit: LiveUnit | undefined;
+}
+
+/**
+ * A player lost their connection. The game is paused and ends in the other player's
+ * favour unless they reconnect within the given time.
+ */
+export interface PlayerDisconnectedEvent {
+  sessionId: string;
+  userId: string;
+  reconnectWithinSecs: number;
+}
+
+/** A disconnected player is back and the game resumes */
+export interface PlayerReconnectedEvent {
+  sessionId: string;
+  userId: string;
+}
+
+/** A unit as it stands, with the rest of its order for the player's own units */
+export interface UnitSnapshot {
+  unit: LiveUnit | undefined;
+  waypoints: Position[];
+  orderDelaySecs: number;
+  /** unset while the unit stands still */
+  movement: UnitMovementStarted | undefined;
+}
+
+export interface ObjectiveSnapshot {
+  objectiveId: string;
+  letter: string;
+  position: Position | undefined;
+  state: ObjectiveState;
+  progress: number;
+  owner?: UnitSide | undefined;
+  capturingSide?: UnitSide | undefined;
+}
+
+/** Everything one player may know about a running game, replacing whatever the client had */
+export interface GameSnapshot {
+  sessionId: string;
+  tick: string;
+  /** game clock; stands still while the game is paused */
+  gameTimeSecs: number;
+  units: UnitSnapshot[];
+  objectives: ObjectiveSnapshot[];
+  resources: ResourcesUpdate | undefined;
+  /** includes areas built during the game */
+  areas: ScenarioArea[];
+  spawnPoints: SpawnPoint[];
+}
+
+/** Fields of a unit that changed during a tick; unset fields are unchanged */
+export interface UnitDelta {
+  unitId: string;
+  morale?: number | undefined;
+  suppression?: number | undefined;
+  routing?: boolean | undefined;
+  health?: number | undefined;
+}
+
+/** Every unit change a player can see, batched once per tick */
+export interface TickUpdate {
+  sessionId: string;
+  tick: string;
+  units: UnitDelta[];
+}
+
+/**
+ * A unit set off, or changed speed or direction. From `started_at_secs` on it moves from
+ * `start` along `path` at constant speed until `eta_secs`; the server only sends another
+ * one when that stops being true, or the unit is more than 50 m from where this puts it.
+ * An empty path means the unit stopped at `start`.
+ * The enemy only ever sees the next waypoint of the path.
+ */
+export interface UnitMovementStarted {
+  sessionId: string;
+  unitId: string;
+  start: Position | undefined;
+  path: Position[];
+  speedKmPerSec: number;
+  /** game clock; later than now while the order is delayed */
+  startedAtSecs: number;
+  etaSecs: number;
+}
+
 function createBaseStartSessionRequest(): StartSessionRequest {
   return { userId: "", scenarioId: "" };
 }
@@ -622,7 +1120,17 @@ export const SessionReadyEvent: MessageFns<SessionReadyEvent> = {
 };
 
 function createBaseWsClientMessage(): WsClientMessage {
-  return { ping: undefined, moveUnit: undefined };
+  return {
+    ping: undefined,
+    moveUnit: undefined,
+    surrender: undefined,
+    stopUnit: undefined,
+    moveUnitPath: undefined,
+    fireMission: undefined,
+    build: undefined,
+    buildUnit: undefined,
+    requestSnapshot: undefined,
+  };
 }
 
 export const WsClientMessage: MessageFns<WsClientMessage> = {
@@ -633,6 +1141,27 @@ export const WsClientMessage: MessageFns<WsClientMessage> = {
     if (message.moveUnit !== undefined) {
       MoveUnitRequest.encode(message.moveUnit, writer.uint32(18).fork()).join();
     }
+    if (message.surrender !== undefined) {
+      SurrenderRequest.encode(message.surrender, writer.uint32(26).fork()).join();
+    }
+    if (message.stopUnit !== undefined) {
+      StopUnitRequest.encode(message.stopUnit, writer.uint32(34).fork()).join();
+    }
+    if (message.moveUnitPath !== undefined) {
+      MoveUnitPathRequest.encode(message.moveUnitPath, writer.uint32(42).fork()).join();
+    }
+    if (message.fireMission !== undefined) {
+      FireMissionRequest.encode(message.fireMission, writer.uint32(50).fork()).join();
+    }
+    if (message.build !== undefined) {
+      BuildRequest.encode(message.build, writer.uint32(58).fork()).join();
+    }
+    if (message.buildUnit !== undefined) {
+      BuildUnitRequest.encode(message.buildUnit, writer.uint32(66).fork()).join();
+    }
+    if (message.requestSnapshot !== undefined) {
+      RequestSnapshot.encode(message.requestSnapshot, writer.uint32(74).fork()).join();
+    }
     return writer;
   },
 
@@ -659,6 +1188,62 @@ export const WsClientMessage: MessageFns<WsClientMessage> = {
           message.moveUnit = MoveUnitRequest.decode(reader, reader.uint32());
           continue;
         }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.surrender = SurrenderRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.stopUnit = StopUnitRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.moveUnitPath = MoveUnitPathRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.fireMission = FireMissionRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.build = BuildRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.buildUnit = BuildUnitRequest.decode(reader, reader.uint32());
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.requestSnapshot = RequestSnapshot.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -672,6 +1257,13 @@ export const WsClientMessage: MessageFns<WsClientMessage> = {
     return {
       ping: isSet(object.ping) ? globalThis.String(object.ping) : undefined,
       moveUnit: isSet(object.moveUnit) ? MoveUnitRequest.fromJSON(object.moveUnit) : undefined,
+      surrender: isSet(object.surrender) ? SurrenderRequest.fromJSON(object.surrender) : undefined,
+      stopUnit: isSet(object.stopUnit) ? StopUnitRequest.fromJSON(object.stopUnit) : undefined,
+      moveUnitPath: isSet(object.moveUnitPath) ? MoveUnitPathRequest.fromJSON(object.moveUnitPath) : undefined,
+      fireMission: isSet(object.fireMission) ? FireMissionRequest.fromJSON(object.fireMission) : undefined,
+      build: isSet(object.build) ? BuildRequest.fromJSON(object.build) : undefined,
+      buildUnit: isSet(object.buildUnit) ? BuildUnitRequest.fromJSON(object.buildUnit) : undefined,
+      requestSnapshot: isSet(object.requestSnapshot) ? RequestSnapshot.fromJSON(object.requestSnapshot) : undefined,
     };
   },
 
@@ -683,6 +1275,27 @@ export const WsClientMessage: MessageFns<WsClientMessage> = {
     if (message.moveUnit !== undefined) {
       obj.moveUnit = MoveUnitRequest.toJSON(message.moveUnit);
     }
+    if (message.surrender !== undefined) {
+      obj.surrender = SurrenderRequest.toJSON(message.surrender);
+    }
+    if (message.stopUnit !== undefined) {
+      obj.stopUnit = StopUnitRequest.toJSON(message.stopUnit);
+    }
+    if (message.moveUnitPath !== undefined) {
+      obj.moveUnitPath = MoveUnitPathRequest.toJSON(message.moveUnitPath);
+    }
+    if (message.fireMission !== undefined) {
+      obj.fireMission = FireMissionRequest.toJSON(message.fireMission);
+    }
+    if (message.build !== undefined) {
+      obj.build = BuildRequest.toJSON(message.build);
+    }
+    if (message.buildUnit !== undefined) {
+      obj.buildUnit = BuildUnitRequest.toJSON(message.buildUnit);
+    }
+    if (message.requestSnapshot !== undefined) {
+      obj.requestSnapshot = RequestSnapshot.toJSON(message.requestSnapshot);
+    }
     return obj;
   },
 
@@ -695,6 +1308,27 @@ export const WsClientMessage: MessageFns<WsClientMessage> = {
     message.moveUnit = (object.moveUnit !== undefined && object.moveUnit !== null)
       ? MoveUnitRequest.fromPartial(object.moveUnit)
       : undefined;
+    message.surrender = (object.surrender !== undefined && object.surrender !== null)
+      ? SurrenderRequest.fromPartial(object.surrender)
+      : undefined;
+    message.stopUnit = (object.stopUnit !== undefined && object.stopUnit !== null)
+      ? StopUnitRequest.fromPartial(object.stopUnit)
+      : undefined;
+    message.moveUnitPath = (object.moveUnitPath !== undefined && object.moveUnitPath !== null)
+      ? MoveUnitPathRequest.fromPartial(object.moveUnitPath)
+      : undefined;
+    message.fireMission = (object.fireMission !== undefined && object.fireMission !== null)
+      ? FireMissionRequest.fromPartial(object.fireMission)
+      : undefined;
+    message.build = (object.build !== undefined && object.build !== null)
+      ? BuildRequest.fromPartial(object.build)
+      : undefined;
+    message.buildUnit = (object.buildUnit !== undefined && object.buildUnit !== null)
+      ? BuildUnitRequest.fromPartial(object.buildUnit)
+      : undefined;
+    message.requestSnapshot = (object.requestSnapshot !== undefined && object.requestSnapshot !== null)
+      ? RequestSnapshot.fromPartial(object.requestSnapshot)
+      : undefined;
     return message;
   },
 };
@@ -850,11 +1484,48 @@ export const GameEndedEvent: MessageFns<GameEndedEvent> = {
 };
 
 function createBaseWsServerMessage(): WsServerMessage {
-  return { sessionReady: undefined, gameStarted: undefined, gameEnded: undefined, unitMoved: undefined };
+  return {
+    sequence: "0",
+    tick: "0",
+    gameTimeSecs: 0,
+    sessionReady: undefined,
+    gameStarted: undefined,
+    gameEnded: undefined,
+    gameState: undefined,
+    unitDestroyed: undefined,
+    unitSpotted: undefined,
+    unitLost: undefined,
+    objectiveStateChanged: undefined,
+    commandRejected: undefined,
+    unitOrderCancelled: undefined,
+    unitPathUpdated: undefined,
+    fireMissionLaunched: undefined,
+    shellImpact: undefined,
+    constructionStarted: undefined,
+    constructionAbandoned: undefined,
+    areaCreated: undefined,
+    headquartersLost: undefined,
+    resources: undefined,
+    unitSpawned: undefined,
+    playerDisconnected: undefined,
+    playerReconnected: undefined,
+    gameSnapshot: undefined,
+    tickUpdate: undefined,
+    unitMovementStarted: undefined,
+  };
 }
 
 export const WsServerMessage: MessageFns<WsServerMessage> = {
   encode(message: WsServerMessage, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sequence !== "0") {
+      writer.uint32(216).uint64(message.sequence);
+    }
+    if (message.tick !== "0") {
+      writer.uint32(224).uint64(message.tick);
+    }
+    if (message.gameTimeSecs !== 0) {
+      writer.uint32(233).double(message.gameTimeSecs);
+    }
     if (message.sessionReady !== undefined) {
       SessionReadyEvent.encode(message.sessionReady, writer.uint32(10).fork()).join();
     }
@@ -864,8 +1535,68 @@ export const WsServerMessage: MessageFns<WsServerMessage> = {
     if (message.gameEnded !== undefined) {
       GameEndedEvent.encode(message.gameEnded, writer.uint32(26).fork()).join();
     }
-    if (message.unitMoved !== undefined) {
-      MoveUnitBroadcast.encode(message.unitMoved, writer.uint32(34).fork()).join();
+    if (message.gameState !== undefined) {
+      GameStateEvent.encode(message.gameState, writer.uint32(42).fork()).join();
+    }
+    if (message.unitDestroyed !== undefined) {
+      UnitDestroyedEvent.encode(message.unitDestroyed, writer.uint32(58).fork()).join();
+    }
+    if (message.unitSpotted !== undefined) {
+      UnitSpottedEvent.encode(message.unitSpotted, writer.uint32(66).fork()).join();
+    }
+    if (message.unitLost !== undefined) {
+      UnitLostEvent.encode(message.unitLost, writer.uint32(74).fork()).join();
+    }
+    if (message.objectiveStateChanged !== undefined) {
+      ObjectiveStateChangedEvent.encode(message.objectiveStateChanged, writer.uint32(82).fork()).join();
+    }
+    if (message.commandRejected !== undefined) {
+      CommandRejectedEvent.encode(message.commandRejected, writer.uint32(90).fork()).join();
+    }
+    if (message.unitOrderCancelled !== undefined) {
+      UnitOrderCancelledEvent.encode(message.unitOrderCancelled, writer.uint32(98).fork()).join();
+    }
+    if (message.unitPathUpdated !== undefined) {
+      UnitPathUpdatedEvent.encode(message.unitPathUpdated, writer.uint32(106).fork()).join();
+    }
+    if (message.fireMissionLaunched !== undefined) {
+      FireMissionLaunchedEvent.encode(message.fireMissionLaunched, writer.uint32(114).fork()).join();
+    }
+    if (message.shellImpact !== undefined) {
+      ShellImpactEvent.encode(message.shellImpact, writer.uint32(122).fork()).join();
+    }
+    if (message.constructionStarted !== undefined) {
+      ConstructionStartedEvent.encode(message.constructionStarted, writer.uint32(130).fork()).join();
+    }
+    if (message.constructionAbandoned !== undefined) {
+      ConstructionAbandonedEvent.encode(message.constructionAbandoned, writer.uint32(138).fork()).join();
+    }
+    if (message.areaCreated !== undefined) {
+      AreaCreatedEvent.encode(message.areaCreated, writer.uint32(146).fork()).join();
+    }
+    if (message.headquartersLost !== undefined) {
+      HeadquartersLostEvent.encode(message.headquartersLost, writer.uint32(154).fork()).join();
+    }
+    if (message.resources !== undefined) {
+      ResourcesUpdate.encode(message.resources, writer.uint32(162).fork()).join();
+    }
+    if (message.unitSpawned !== undefined) {
+      UnitSpawnedEvent.encode(message.unitSpawned, writer.uint32(170).fork()).join();
+    }
+    if (message.playerDisconnected !== undefined) {
+      PlayerDisconnectedEvent.encode(message.playerDisconnected, writer.uint32(186).fork()).join();
+    }
+    if (message.playerReconnected !== undefined) {
+      PlayerReconnectedEvent.encode(message.playerReconnected, writer.uint32(194).fork()).join();
+    }
+    if (message.gameSnapshot !== undefined) {
+      GameSnapshot.encode(message.gameSnapshot, writer.uint32(202).fork()).join();
+    }
+    if (message.tickUpdate !== undefined) {
+      TickUpdate.encode(message.tickUpdate, writer.uint32(210).fork()).join();
+    }
+    if (message.unitMovementStarted !== undefined) {
+      UnitMovementStarted.encode(message.unitMovementStarted, writer.uint32(242).fork()).join();
     }
     return writer;
   },
@@ -877,6 +1608,30 @@ export const WsServerMessage: MessageFns<WsServerMessage> = {
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
+        case 27: {
+          if (tag !== 216) {
+            break;
+          }
+
+          message.sequence = reader.uint64().toString();
+          continue;
+        }
+        case 28: {
+          if (tag !== 224) {
+            break;
+          }
+
+          message.tick = reader.uint64().toString();
+          continue;
+        }
+        case 29: {
+          if (tag !== 233) {
+            break;
+          }
+
+          message.gameTimeSecs = reader.double();
+          continue;
+        }
         case 1: {
           if (tag !== 10) {
             break;
@@ -901,45 +1656,319 @@ export const WsServerMessage: MessageFns<WsServerMessage> = {
           message.gameEnded = GameEndedEvent.decode(reader, reader.uint32());
           continue;
         }
-        case 4: {
-          if (tag !== 34) {
+        case 5: {
+          if (tag !== 42) {
             break;
           }
 
-          message.unitMoved = MoveUnitBroadcast.decode(reader, reader.uint32());
+          message.gameState = GameStateEvent.decode(reader, reader.uint32());
           continue;
         }
-      }
-      if ((tag & 7) === 4 || tag === 0) {
-        break;
-      }
-      reader.skip(tag & 7);
-    }
-    return message;
-  },
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
 
-  fromJSON(object: any): WsServerMessage {
-    return {
-      sessionReady: isSet(object.sessionReady) ? SessionReadyEvent.fromJSON(object.sessionReady) : undefined,
-      gameStarted: isSet(object.gameStarted) ? GameStartedEvent.fromJSON(object.gameStarted) : undefined,
-      gameEnded: isSet(object.gameEnded) ? GameEndedEvent.fromJSON(object.gameEnded) : undefined,
-      unitMoved: isSet(object.unitMoved) ? MoveUnitBroadcast.fromJSON(object.unitMoved) : undefined,
-    };
-  },
+          message.unitDestroyed = UnitDestroyedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
 
-  toJSON(message: WsServerMessage): unknown {
-    const obj: any = {};
-    if (message.sessionReady !== undefined) {
-      obj.sessionReady = SessionReadyEvent.toJSON(message.sessionReady);
-    }
-    if (message.gameStarted !== undefined) {
-      obj.gameStarted = GameStartedEvent.toJSON(message.gameStarted);
-    }
-    if (message.gameEnded !== undefined) {
-      obj.gameEnded = GameEndedEvent.toJSON(message.gameEnded);
+          message.unitSpotted = UnitSpottedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.unitLost = UnitLostEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 10: {
+          if (tag !== 82) {
+            break;
+          }
+
+          message.objectiveStateChanged = ObjectiveStateChangedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 11: {
+          if (tag !== 90) {
+            break;
+          }
+
+          message.commandRejected = CommandRejectedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 12: {
+          if (tag !== 98) {
+            break;
+          }
+
+          message.unitOrderCancelled = UnitOrderCancelledEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 13: {
+          if (tag !== 106) {
+            break;
+          }
+
+          message.unitPathUpdated = UnitPathUpdatedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 14: {
+          if (tag !== 114) {
+            break;
+          }
+
+          message.fireMissionLaunched = FireMissionLaunchedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 15: {
+          if (tag !== 122) {
+            break;
+          }
+
+          message.shellImpact = ShellImpactEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 16: {
+          if (tag !== 130) {
+            break;
+          }
+
+          message.constructionStarted = ConstructionStartedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 17: {
+          if (tag !== 138) {
+            break;
+          }
+
+          message.constructionAbandoned = ConstructionAbandonedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 18: {
+          if (tag !== 146) {
+            break;
+          }
+
+          message.areaCreated = AreaCreatedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 19: {
+          if (tag !== 154) {
+            break;
+          }
+
+          message.headquartersLost = HeadquartersLostEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 20: {
+          if (tag !== 162) {
+            break;
+          }
+
+          message.resources = ResourcesUpdate.decode(reader, reader.uint32());
+          continue;
+        }
+        case 21: {
+          if (tag !== 170) {
+            break;
+          }
+
+          message.unitSpawned = UnitSpawnedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 23: {
+          if (tag !== 186) {
+            break;
+          }
+
+          message.playerDisconnected = PlayerDisconnectedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 24: {
+          if (tag !== 194) {
+            break;
+          }
+
+          message.playerReconnected = PlayerReconnectedEvent.decode(reader, reader.uint32());
+          continue;
+        }
+        case 25: {
+          if (tag !== 202) {
+            break;
+          }
+
+          message.gameSnapshot = GameSnapshot.decode(reader, reader.uint32());
+          continue;
+        }
+        case 26: {
+          if (tag !== 210) {
+            break;
+          }
+
+          message.tickUpdate = TickUpdate.decode(reader, reader.uint32());
+          continue;
+        }
+        case 30: {
+          if (tag !== 242) {
+            break;
+          }
+
+          message.unitMovementStarted = UnitMovementStarted.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): WsServerMessage {
+    return {
+      sequence: isSet(object.sequence) ? globalThis.String(object.sequence) : "0",
+      tick: isSet(object.tick) ? globalThis.String(object.tick) : "0",
+      gameTimeSecs: isSet(object.gameTimeSecs) ? globalThis.Number(object.gameTimeSecs) : 0,
+      sessionReady: isSet(object.sessionReady) ? SessionReadyEvent.fromJSON(object.sessionReady) : undefined,
+      gameStarted: isSet(object.gameStarted) ? GameStartedEvent.fromJSON(object.gameStarted) : undefined,
+      gameEnded: isSet(object.gameEnded) ? GameEndedEvent.fromJSON(object.gameEnded) : undefined,
+      gameState: isSet(object.gameState) ? GameStateEvent.fromJSON(object.gameState) : undefined,
+      unitDestroyed: isSet(object.unitDestroyed) ? UnitDestroyedEvent.fromJSON(object.unitDestroyed) : undefined,
+      unitSpotted: isSet(object.unitSpotted) ? UnitSpottedEvent.fromJSON(object.unitSpotted) : undefined,
+      unitLost: isSet(object.unitLost) ? UnitLostEvent.fromJSON(object.unitLost) : undefined,
+      objectiveStateChanged: isSet(object.objectiveStateChanged)
+        ? ObjectiveStateChangedEvent.fromJSON(object.objectiveStateChanged)
+        : undefined,
+      commandRejected: isSet(object.commandRejected)
+        ? CommandRejectedEvent.fromJSON(object.commandRejected)
+        : undefined,
+      unitOrderCancelled: isSet(object.unitOrderCancelled)
+        ? UnitOrderCancelledEvent.fromJSON(object.unitOrderCancelled)
+        : undefined,
+      unitPathUpdated: isSet(object.unitPathUpdated)
+        ? UnitPathUpdatedEvent.fromJSON(object.unitPathUpdated)
+        : undefined,
+      fireMissionLaunched: isSet(object.fireMissionLaunched)
+        ? FireMissionLaunchedEvent.fromJSON(object.fireMissionLaunched)
+        : undefined,
+      shellImpact: isSet(object.shellImpact) ? ShellImpactEvent.fromJSON(object.shellImpact) : undefined,
+      constructionStarted: isSet(object.constructionStarted)
+        ? ConstructionStartedEvent.fromJSON(object.constructionStarted)
+        : undefined,
+      constructionAbandoned: isSet(object.constructionAbandoned)
+        ? ConstructionAbandonedEvent.fromJSON(object.constructionAbandoned)
+        : undefined,
+      areaCreated: isSet(object.areaCreated) ? AreaCreatedEvent.fromJSON(object.areaCreated) : undefined,
+      headquartersLost: isSet(object.headquartersLost)
+        ? HeadquartersLostEvent.fromJSON(object.headquartersLost)
+        : undefined,
+      resources: isSet(object.resources) ? ResourcesUpdate.fromJSON(object.resources) : undefined,
+      unitSpawned: isSet(object.unitSpawned) ? UnitSpawnedEvent.fromJSON(object.unitSpawned) : undefined,
+      playerDisconnected: isSet(object.playerDisconnected)
+        ? PlayerDisconnectedEvent.fromJSON(object.playerDisconnected)
+        : undefined,
+      playerReconnected: isSet(object.playerReconnected)
+        ? PlayerReconnectedEvent.fromJSON(object.playerReconnected)
+        : undefined,
+      gameSnapshot: isSet(object.gameSnapshot) ? GameSnapshot.fromJSON(object.gameSnapshot) : undefined,
+      tickUpdate: isSet(object.tickUpdate) ? TickUpdate.fromJSON(object.tickUpdate) : undefined,
+      unitMovementStarted: isSet(object.unitMovementStarted)
+        ? UnitMovementStarted.fromJSON(object.unitMovementStarted)
+        : undefined,
+    };
+  },
+
+  toJSON(message: WsServerMessage): unknown {
+    const obj: any = {};
+    if (message.sequence !== "0") {
+      obj.sequence = message.sequence;
+    }
+    if (message.tick !== "0") {
+      obj.tick = message.tick;
+    }
+    if (message.gameTimeSecs !== 0) {
+      obj.gameTimeSecs = message.gameTimeSecs;
+    }
+    if (message.sessionReady !== undefined) {
+      obj.sessionReady = SessionReadyEvent.toJSON(message.sessionReady);
+    }
+    if (message.gameStarted !== undefined) {
+      obj.gameStarted = GameStartedEvent.toJSON(message.gameStarted);
+    }
+    if (message.gameEnded !== undefined) {
+      obj.gameEnded = GameEndedEvent.toJSON(message.gameEnded);
+    }
+    if (message.gameState !== undefined) {
+      obj.gameState = GameStateEvent.toJSON(message.gameState);
+    }
+    if (message.unitDestroyed !== undefined) {
+      obj.unitDestroyed = UnitDestroyedEvent.toJSON(message.unitDestroyed);
+    }
+    if (message.unitSpotted !== undefined) {
+      obj.unitSpotted = UnitSpottedEvent.toJSON(message.unitSpotted);
+    }
+    if (message.unitLost !== undefined) {
+      obj.unitLost = UnitLostEvent.toJSON(message.unitLost);
+    }
+    if (message.objectiveStateChanged !== undefined) {
+      obj.objectiveStateChanged = ObjectiveStateChangedEvent.toJSON(message.objectiveStateChanged);
+    }
+    if (message.commandRejected !== undefined) {
+      obj.commandRejected = CommandRejectedEvent.toJSON(message.commandRejected);
     }
-    if (message.unitMoved !== undefined) {
-      obj.unitMoved = MoveUnitBroadcast.toJSON(message.unitMoved);
+    if (message.unitOrderCancelled !== undefined) {
+      obj.unitOrderCancelled = UnitOrderCancelledEvent.toJSON(message.unitOrderCancelled);
+    }
+    if (message.unitPathUpdated !== undefined) {
+      obj.unitPathUpdated = UnitPathUpdatedEvent.toJSON(message.unitPathUpdated);
+    }
+    if (message.fireMissionLaunched !== undefined) {
+      obj.fireMissionLaunched = FireMissionLaunchedEvent.toJSON(message.fireMissionLaunched);
+    }
+    if (message.shellImpact !== undefined) {
+      obj.shellImpact = ShellImpactEvent.toJSON(message.shellImpact);
+    }
+    if (message.constructionStarted !== undefined) {
+      obj.constructionStarted = ConstructionStartedEvent.toJSON(message.constructionStarted);
+    }
+    if (message.constructionAbandoned !== undefined) {
+      obj.constructionAbandoned = ConstructionAbandonedEvent.toJSON(message.constructionAbandoned);
+    }
+    if (message.areaCreated !== undefined) {
+      obj.areaCreated = AreaCreatedEvent.toJSON(message.areaCreated);
+    }
+    if (message.headquartersLost !== undefined) {
+      obj.headquartersLost = HeadquartersLostEvent.toJSON(message.headquartersLost);
+    }
+    if (message.resources !== undefined) {
+      obj.resources = ResourcesUpdate.toJSON(message.resources);
+    }
+    if (message.unitSpawned !== undefined) {
+      obj.unitSpawned = UnitSpawnedEvent.toJSON(message.unitSpawned);
+    }
+    if (message.playerDisconnected !== undefined) {
+      obj.playerDisconnected = PlayerDisconnectedEvent.toJSON(message.playerDisconnected);
+    }
+    if (message.playerReconnected !== undefined) {
+      obj.playerReconnected = PlayerReconnectedEvent.toJSON(message.playerReconnected);
+    }
+    if (message.gameSnapshot !== undefined) {
+      obj.gameSnapshot = GameSnapshot.toJSON(message.gameSnapshot);
+    }
+    if (message.tickUpdate !== undefined) {
+      obj.tickUpdate = TickUpdate.toJSON(message.tickUpdate);
+    }
+    if (message.unitMovementStarted !== undefined) {
+      obj.unitMovementStarted = UnitMovementStarted.toJSON(message.unitMovementStarted);
     }
     return obj;
   },
@@ -949,6 +1978,9 @@ export const WsServerMessage: MessageFns<WsServerMessage> = {
   },
   fromPartial<I extends Exact<DeepPartial<WsServerMessage>, I>>(object: I): WsServerMessage {
     const message = createBaseWsServerMessage();
+    message.sequence = object.sequence ?? "0";
+    message.tick = object.tick ?? "0";
+    message.gameTimeSecs = object.gameTimeSecs ?? 0;
     message.sessionReady = (object.sessionReady !== undefined && object.sessionReady !== null)
       ? SessionReadyEvent.fromPartial(object.sessionReady)
       : undefined;
@@ -958,15 +1990,77 @@ export const WsServerMessage: MessageFns<WsServerMessage> = {
     message.gameEnded = (object.gameEnded !== undefined && object.gameEnded !== null)
       ? GameEndedEvent.fromPartial(object.gameEnded)
       : undefined;
-    message.unitMoved = (object.unitMoved !== undefined && object.unitMoved !== null)
-      ? MoveUnitBroadcast.fromPartial(object.unitMoved)
+    message.gameState = (object.gameState !== undefined && object.gameState !== null)
+      ? GameStateEvent.fromPartial(object.gameState)
+      : undefined;
+    message.unitDestroyed = (object.unitDestroyed !== undefined && object.unitDestroyed !== null)
+      ? UnitDestroyedEvent.fromPartial(object.unitDestroyed)
+      : undefined;
+    message.unitSpotted = (object.unitSpotted !== undefined && object.unitSpotted !== null)
+      ? UnitSpottedEvent.fromPartial(object.unitSpotted)
+      : undefined;
+    message.unitLost = (object.unitLost !== undefined && object.unitLost !== null)
+      ? UnitLostEvent.fromPartial(object.unitLost)
+      : undefined;
+    message.objectiveStateChanged =
+      (object.objectiveStateChanged !== undefined && object.objectiveStateChanged !== null)
+        ? ObjectiveStateChangedEvent.fromPartial(object.objectiveStateChanged)
+        : undefined;
+    message.commandRejected = (object.commandRejected !== undefined && object.commandRejected !== null)
+      ? CommandRejectedEvent.fromPartial(object.commandRejected)
+      : undefined;
+    message.unitOrderCancelled = (object.unitOrderCancelled !== undefined && object.unitOrderCancelled !== null)
+      ? UnitOrderCancelledEvent.fromPartial(object.unitOrderCancelled)
+      : undefined;
+    message.unitPathUpdated = (object.unitPathUpdated !== undefined && object.unitPathUpdated !== null)
+      ? UnitPathUpdatedEvent.fromPartial(object.unitPathUpdated)
+      : undefined;
+    message.fireMissionLaunched = (object.fireMissionLaunched !== undefined && object.fireMissionLaunched !== null)
+      ? FireMissionLaunchedEvent.fromPartial(object.fireMissionLaunched)
+      : undefined;
+    message.shellImpact = (object.shellImpact !== undefined && object.shellImpact !== null)
+      ? ShellImpactEvent.fromPartial(object.shellImpact)
+      : undefined;
+    message.constructionStarted = (object.constructionStarted !== undefined && object.constructionStarted !== null)
+      ? ConstructionStartedEvent.fromPartial(object.constructionStarted)
+      : undefined;
+    message.constructionAbandoned =
+      (object.constructionAbandoned !== undefined && object.constructionAbandoned !== null)
+        ? ConstructionAbandonedEvent.fromPartial(object.constructionAbandoned)
+        : undefined;
+    message.areaCreated = (object.areaCreated !== undefined && object.areaCreated !== null)
+      ? AreaCreatedEvent.fromPartial(object.areaCreated)
+      : undefined;
+    message.headquartersLost = (object.headquartersLost !== undefined && object.headquartersLost !== null)
+      ? HeadquartersLostEvent.fromPartial(object.headquartersLost)
+      : undefined;
+    message.resources = (object.resources !== undefined && object.resources !== null)
+      ? ResourcesUpdate.fromPartial(object.resources)
+      : undefined;
+    message.unitSpawned = (object.unitSpawned !== undefined && object.unitSpawned !== null)
+      ? UnitSpawnedEvent.fromPartial(object.unitSpawned)
+      : undefined;
+    message.playerDisconnected = (object.playerDisconnected !== undefined && object.playerDisconnected !== null)
+      ? PlayerDisconnectedEvent.fromPartial(object.playerDisconnected)
+      : undefined;
+    message.playerReconnected = (object.playerReconnected !== undefined && object.playerReconnected !== null)
+      ? PlayerReconnectedEvent.fromPartial(object.playerReconnected)
+      : undefined;
+    message.gameSnapshot = (object.gameSnapshot !== undefined && object.gameSnapshot !== null)
+      ? GameSnapshot.fromPartial(object.gameSnapshot)
+      : undefined;
+    message.tickUpdate = (object.tickUpdate !== undefined && object.tickUpdate !== null)
+      ? TickUpdate.fromPartial(object.tickUpdate)
+      : undefined;
+    message.unitMovementStarted = (object.unitMovementStarted !== undefined && object.unitMovementStarted !== null)
+      ? UnitMovementStarted.fromPartial(object.unitMovementStarted)
       : undefined;
     return message;
   },
 };
 
 function createBaseMoveUnitRequest(): MoveUnitRequest {
-  return { sessionId: "", unitId: "", targetLat: 0, targetLon: 0 };
+  return { sessionId: "", unitId: "", targetLat: 0, targetLon: 0, fastestRoute: false };
 }
 
 export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
@@ -983,6 +2077,9 @@ export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
     if (message.targetLon !== 0) {
       writer.uint32(33).double(message.targetLon);
     }
+    if (message.fastestRoute !== false) {
+      writer.uint32(40).bool(message.fastestRoute);
+    }
     return writer;
   },
 
@@ -1025,6 +2122,14 @@ export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
           message.targetLon = reader.double();
           continue;
         }
+        case 5: {
+          if (tag !== 40) {
+            break;
+          }
+
+          message.fastestRoute = reader.bool();
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1040,6 +2145,7 @@ export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
       unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
       targetLat: isSet(object.targetLat) ? globalThis.Number(object.targetLat) : 0,
       targetLon: isSet(object.targetLon) ? globalThis.Number(object.targetLon) : 0,
+      fastestRoute: isSet(object.fastestRoute) ? globalThis.Boolean(object.fastestRoute) : false,
     };
   },
 
@@ -1057,6 +2163,9 @@ export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
     if (message.targetLon !== 0) {
       obj.targetLon = message.targetLon;
     }
+    if (message.fastestRoute !== false) {
+      obj.fastestRoute = message.fastestRoute;
+    }
     return obj;
   },
 
@@ -1069,35 +2178,36 @@ export const MoveUnitRequest: MessageFns<MoveUnitRequest> = {
     message.unitId = object.unitId ?? "";
     message.targetLat = object.targetLat ?? 0;
     message.targetLon = object.targetLon ?? 0;
+    message.fastestRoute = object.fastestRoute ?? false;
     return message;
   },
 };
 
-function createBaseMoveUnitBroadcast(): MoveUnitBroadcast {
-  return { sessionId: "", unitId: "", targetLat: 0, targetLon: 0 };
+function createBaseMoveUnitPathRequest(): MoveUnitPathRequest {
+  return { sessionId: "", unitId: "", waypoints: [], append: false };
 }
 
-export const MoveUnitBroadcast: MessageFns<MoveUnitBroadcast> = {
-  encode(message: MoveUnitBroadcast, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const MoveUnitPathRequest: MessageFns<MoveUnitPathRequest> = {
+  encode(message: MoveUnitPathRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     if (message.sessionId !== "") {
       writer.uint32(10).string(message.sessionId);
     }
     if (message.unitId !== "") {
       writer.uint32(18).string(message.unitId);
     }
-    if (message.targetLat !== 0) {
-      writer.uint32(25).double(message.targetLat);
+    for (const v of message.waypoints) {
+      Position.encode(v!, writer.uint32(26).fork()).join();
     }
-    if (message.targetLon !== 0) {
-      writer.uint32(33).double(message.targetLon);
+    if (message.append !== false) {
+      writer.uint32(32).bool(message.append);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): MoveUnitBroadcast {
+  decode(input: BinaryReader | Uint8Array, length?: number): MoveUnitPathRequest {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseMoveUnitBroadcast();
+    const message = createBaseMoveUnitPathRequest();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -1118,19 +2228,19 @@ export const MoveUnitBroadcast: MessageFns<MoveUnitBroadcast> = {
           continue;
         }
         case 3: {
-          if (tag !== 25) {
+          if (tag !== 26) {
             break;
           }
 
-          message.targetLat = reader.double();
+          message.waypoints.push(Position.decode(reader, reader.uint32()));
           continue;
         }
         case 4: {
-          if (tag !== 33) {
+          if (tag !== 32) {
             break;
           }
 
-          message.targetLon = reader.double();
+          message.append = reader.bool();
           continue;
         }
       }
@@ -1142,16 +2252,18 @@ export const MoveUnitBroadcast: MessageFns<MoveUnitBroadcast> = {
     return message;
   },
 
-  fromJSON(object: any): MoveUnitBroadcast {
+  fromJSON(object: any): MoveUnitPathRequest {
     return {
       sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
       unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
-      targetLat: isSet(object.targetLat) ? globalThis.Number(object.targetLat) : 0,
-      targetLon: isSet(object.targetLon) ? globalThis.Number(object.targetLon) : 0,
+      waypoints: globalThis.Array.isArray(object?.waypoints)
+        ? object.waypoints.map((e: any) => Position.fromJSON(e))
+        : [],
+      append: isSet(object.append) ? globalThis.Boolean(object.append) : false,
     };
   },
 
-  toJSON(message: MoveUnitBroadcast): unknown {
+  toJSON(message: MoveUnitPathRequest): unknown {
     const obj: any = {};
     if (message.sessionId !== "") {
       obj.sessionId = message.sessionId;
@@ -1159,24 +2271,3472 @@ export const MoveUnitBroadcast: MessageFns<MoveUnitBroadcast> = {
     if (message.unitId !== "") {
       obj.unitId = message.unitId;
     }
-    if (message.targetLat !== 0) {
-      obj.targetLat = message.targetLat;
+    if (message.waypoints?.length) {
+      obj.waypoints = message.waypoints.map((e) => Position.toJSON(e));
     }
-    if (message.targetLon !== 0) {
-      obj.targetLon = message.targetLon;
+    if (message.append !== false) {
+      obj.append = message.append;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<MoveUnitBroadcast>, I>>(base?: I): MoveUnitBroadcast {
-    return MoveUnitBroadcast.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<MoveUnitPathRequest>, I>>(base?: I): MoveUnitPathRequest {
+    return MoveUnitPathRequest.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<MoveUnitBroadcast>, I>>(object: I): MoveUnitBroadcast {
-    const message = createBaseMoveUnitBroadcast();
+  fromPartial<I extends Exact<DeepPartial<MoveUnitPathRequest>, I>>(object: I): MoveUnitPathRequest {
+    const message = createBaseMoveUnitPathRequest();
     message.sessionId = object.sessionId ?? "";
     message.unitId = object.unitId ?? "";
-    message.targetLat = object.targetLat ?? 0;
-    message.targetLon = object.targetLon ?? 0;
+    message.waypoints = object.waypoints?.map((e) => Position.fromPartial(e)) || [];
+    message.append = object.append ?? false;
+    return message;
+  },
+};
+
+function createBaseStopUnitRequest(): StopUnitRequest {
+  return { sessionId: "", unitId: "" };
+}
+
+export const StopUnitRequest: MessageFns<StopUnitRequest> = {
+  encode(message: StopUnitRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): StopUnitRequest {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseStopUnitRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): StopUnitRequest {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+    };
+  },
+
+  toJSON(message: StopUnitRequest): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<StopUnitRequest>, I>>(base?: I): StopUnitRequest {
+    return StopUnitRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<StopUnitRequest>, I>>(object: I): StopUnitRequest {
+    const message = createBaseStopUnitRequest();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    return message;
+  },
+};
+
+function createBaseFireMissionRequest(): FireMissionRequest {
+  return { sessionId: "", unitId: "", targetLat: 0, targetLon: 0 };
+}
+
+export const FireMissionRequest: MessageFns<FireMissionRequest> = {
+  encode(message: FireMissionRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.targetLat !== 0) {
+      writer.uint32(25).double(message.targetLat);
+    }
+    if (message.targetLon !== 0) {
+      writer.uint32(33).double(message.targetLon);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): FireMissionRequest {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFireMissionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 25) {
+            break;
+          }
+
+          message.targetLat = reader.double();
+          continue;
+        }
+        case 4: {
+          if (tag !== 33) {
+            break;
+          }
+
+          message.targetLon = reader.double();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FireMissionRequest {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      targetLat: isSet(object.targetLat) ? globalThis.Number(object.targetLat) : 0,
+      targetLon: isSet(object.targetLon) ? globalThis.Number(object.targetLon) : 0,
+    };
+  },
+
+  toJSON(message: FireMissionRequest): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.targetLat !== 0) {
+      obj.targetLat = message.targetLat;
+    }
+    if (message.targetLon !== 0) {
+      obj.targetLon = message.targetLon;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FireMissionRequest>, I>>(base?: I): FireMissionRequest {
+    return FireMissionRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FireMissionRequest>, I>>(object: I): FireMissionRequest {
+    const message = createBaseFireMissionRequest();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.targetLat = object.targetLat ?? 0;
+    message.targetLon = object.targetLon ?? 0;
+    return message;
+  },
+};
+
+function createBaseBuildRequest(): BuildRequest {
+  return { sessionId: "", unitId: "", areaType: "", outline: undefined };
+}
+
+export const BuildRequest: MessageFns<BuildRequest> = {
+  encode(message: BuildRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.areaType !== "") {
+      writer.uint32(26).string(message.areaType);
+    }
+    if (message.outline !== undefined) {
+      Ring.encode(message.outline, writer.uint32(34).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): BuildRequest {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBuildRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.areaType = reader.string();
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.outline = Ring.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BuildRequest {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      areaType: isSet(object.areaType) ? globalThis.String(object.areaType) : "",
+      outline: isSet(object.outline) ? Ring.fromJSON(object.outline) : undefined,
+    };
+  },
+
+  toJSON(message: BuildRequest): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.areaType !== "") {
+      obj.areaType = message.areaType;
+    }
+    if (message.outline !== undefined) {
+      obj.outline = Ring.toJSON(message.outline);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BuildRequest>, I>>(base?: I): BuildRequest {
+    return BuildRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BuildRequest>, I>>(object: I): BuildRequest {
+    const message = createBaseBuildRequest();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.areaType = object.areaType ?? "";
+    message.outline = (object.outline !== undefined && object.outline !== null)
+      ? Ring.fromPartial(object.outline)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseBuildUnitRequest(): BuildUnitRequest {
+  return { sessionId: "", unitType: "", spawnId: "" };
+}
+
+export const BuildUnitRequest: MessageFns<BuildUnitRequest> = {
+  encode(message: BuildUnitRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitType !== "") {
+      writer.uint32(18).string(message.unitType);
+    }
+    if (message.spawnId !== "") {
+      writer.uint32(26).string(message.spawnId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): BuildUnitRequest {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseBuildUnitRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitType = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.spawnId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): BuildUnitRequest {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitType: isSet(object.unitType) ? globalThis.String(object.unitType) : "",
+      spawnId: isSet(object.spawnId) ? globalThis.String(object.spawnId) : "",
+    };
+  },
+
+  toJSON(message: BuildUnitRequest): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitType !== "") {
+      obj.unitType = message.unitType;
+    }
+    if (message.spawnId !== "") {
+      obj.spawnId = message.spawnId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<BuildUnitRequest>, I>>(base?: I): BuildUnitRequest {
+    return BuildUnitRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<BuildUnitRequest>, I>>(object: I): BuildUnitRequest {
+    const message = createBaseBuildUnitRequest();
+    message.sessionId = object.sessionId ?? "";
+    message.unitType = object.unitType ?? "";
+    message.spawnId = object.spawnId ?? "";
+    return message;
+  },
+};
+
+function createBaseRequestSnapshot(): RequestSnapshot {
+  return { sessionId: "" };
+}
+
+export const RequestSnapshot: MessageFns<RequestSnapshot> = {
+  encode(message: RequestSnapshot, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): RequestSnapshot {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseRequestSnapshot();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): RequestSnapshot {
+    return { sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "" };
+  },
+
+  toJSON(message: RequestSnapshot): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<RequestSnapshot>, I>>(base?: I): RequestSnapshot {
+    return RequestSnapshot.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<RequestSnapshot>, I>>(object: I): RequestSnapshot {
+    const message = createBaseRequestSnapshot();
+    message.sessionId = object.sessionId ?? "";
+    return message;
+  },
+};
+
+function createBaseSurrenderRequest(): SurrenderRequest {
+  return { sessionId: "" };
+}
+
+export const SurrenderRequest: MessageFns<SurrenderRequest> = {
+  encode(message: SurrenderRequest, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SurrenderRequest {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSurrenderRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SurrenderRequest {
+    return { sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "" };
+  },
+
+  toJSON(message: SurrenderRequest): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SurrenderRequest>, I>>(base?: I): SurrenderRequest {
+    return SurrenderRequest.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SurrenderRequest>, I>>(object: I): SurrenderRequest {
+    const message = createBaseSurrenderRequest();
+    message.sessionId = object.sessionId ?? "";
+    return message;
+  },
+};
+
+function createBaseLiveUnit(): LiveUnit {
+  return {
+    unitId: "",
+    position: undefined,
+    unitKey: "",
+    side: 0,
+    icon: "",
+    health: 0,
+    maxHealth: 0,
+    morale: 0,
+    suppression: 0,
+    routing: false,
+  };
+}
+
+export const LiveUnit: MessageFns<LiveUnit> = {
+  encode(message: LiveUnit, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.unitId !== "") {
+      writer.uint32(10).string(message.unitId);
+    }
+    if (message.position !== undefined) {
+      Position.encode(message.position, writer.uint32(18).fork()).join();
+    }
+    if (message.unitKey !== "") {
+      writer.uint32(26).string(message.unitKey);
+    }
+    if (message.side !== 0) {
+      writer.uint32(32).int32(message.side);
+    }
+    if (message.icon !== "") {
+      writer.uint32(42).string(message.icon);
+    }
+    if (message.health !== 0) {
+      writer.uint32(48).uint32(message.health);
+    }
+    if (message.maxHealth !== 0) {
+      writer.uint32(56).uint32(message.maxHealth);
+    }
+    if (message.morale !== 0) {
+      writer.uint32(69).float(message.morale);
+    }
+    if (message.suppression !== 0) {
+      writer.uint32(77).float(message.suppression);
+    }
+    if (message.routing !== false) {
+      writer.uint32(80).bool(message.routing);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): LiveUnit {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLiveUnit();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.position = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.unitKey = reader.string();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.side = reader.int32() as any;
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.icon = reader.string();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.health = reader.uint32();
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.maxHealth = reader.uint32();
+          continue;
+        }
+        case 8: {
+          if (tag !== 69) {
+            break;
+          }
+
+          message.morale = reader.float();
+          continue;
+        }
+        case 9: {
+          if (tag !== 77) {
+            break;
+          }
+
+          message.suppression = reader.float();
+          continue;
+        }
+        case 10: {
+          if (tag !== 80) {
+            break;
+          }
+
+          message.routing = reader.bool();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LiveUnit {
+    return {
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      position: isSet(object.position) ? Position.fromJSON(object.position) : undefined,
+      unitKey: isSet(object.unitKey) ? globalThis.String(object.unitKey) : "",
+      side: isSet(object.side) ? unitSideFromJSON(object.side) : 0,
+      icon: isSet(object.icon) ? globalThis.String(object.icon) : "",
+      health: isSet(object.health) ? globalThis.Number(object.health) : 0,
+      maxHealth: isSet(object.maxHealth) ? globalThis.Number(object.maxHealth) : 0,
+      morale: isSet(object.morale) ? globalThis.Number(object.morale) : 0,
+      suppression: isSet(object.suppression) ? globalThis.Number(object.suppression) : 0,
+      routing: isSet(object.routing) ? globalThis.Boolean(object.routing) : false,
+    };
+  },
+
+  toJSON(message: LiveUnit): unknown {
+    const obj: any = {};
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.position !== undefined) {
+      obj.position = Position.toJSON(message.position);
+    }
+    if (message.unitKey !== "") {
+      obj.unitKey = message.unitKey;
+    }
+    if (message.side !== 0) {
+      obj.side = unitSideToJSON(message.side);
+    }
+    if (message.icon !== "") {
+      obj.icon = message.icon;
+    }
+    if (message.health !== 0) {
+      obj.health = Math.round(message.health);
+    }
+    if (message.maxHealth !== 0) {
+      obj.maxHealth = Math.round(message.maxHealth);
+    }
+    if (message.morale !== 0) {
+      obj.morale = message.morale;
+    }
+    if (message.suppression !== 0) {
+      obj.suppression = message.suppression;
+    }
+    if (message.routing !== false) {
+      obj.routing = message.routing;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<LiveUnit>, I>>(base?: I): LiveUnit {
+    return LiveUnit.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<LiveUnit>, I>>(object: I): LiveUnit {
+    const message = createBaseLiveUnit();
+    message.unitId = object.unitId ?? "";
+    message.position = (object.position !== undefined && object.position !== null)
+      ? Position.fromPartial(object.position)
+      : undefined;
+    message.unitKey = object.unitKey ?? "";
+    message.side = object.side ?? 0;
+    message.icon = object.icon ?? "";
+    message.health = object.health ?? 0;
+    message.maxHealth = object.maxHealth ?? 0;
+    message.morale = object.morale ?? 0;
+    message.suppression = object.suppression ?? 0;
+    message.routing = object.routing ?? false;
+    return message;
+  },
+};
+
+function createBaseGameStateEvent(): GameStateEvent {
+  return { sessionId: "", units: [], objectives: [], areas: [], spawnPoints: [] };
+}
+
+export const GameStateEvent: MessageFns<GameStateEvent> = {
+  encode(message: GameStateEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    for (const v of message.units) {
+      LiveUnit.encode(v!, writer.uint32(18).fork()).join();
+    }
+    for (const v of message.objectives) {
+      Objective.encode(v!, writer.uint32(26).fork()).join();
+    }
+    for (const v of message.areas) {
+      ScenarioArea.encode(v!, writer.uint32(34).fork()).join();
+    }
+    for (const v of message.spawnPoints) {
+      SpawnPoint.encode(v!, writer.uint32(42).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): GameStateEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGameStateEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.units.push(LiveUnit.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.objectives.push(Objective.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.areas.push(ScenarioArea.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.spawnPoints.push(SpawnPoint.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GameStateEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      units: globalThis.Array.isArray(object?.units) ? object.units.map((e: any) => LiveUnit.fromJSON(e)) : [],
+      objectives: globalThis.Array.isArray(object?.objectives)
+        ? object.objectives.map((e: any) => Objective.fromJSON(e))
+        : [],
+      areas: globalThis.Array.isArray(object?.areas) ? object.areas.map((e: any) => ScenarioArea.fromJSON(e)) : [],
+      spawnPoints: globalThis.Array.isArray(object?.spawnPoints)
+        ? object.spawnPoints.map((e: any) => SpawnPoint.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: GameStateEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.units?.length) {
+      obj.units = message.units.map((e) => LiveUnit.toJSON(e));
+    }
+    if (message.objectives?.length) {
+      obj.objectives = message.objectives.map((e) => Objective.toJSON(e));
+    }
+    if (message.areas?.length) {
+      obj.areas = message.areas.map((e) => ScenarioArea.toJSON(e));
+    }
+    if (message.spawnPoints?.length) {
+      obj.spawnPoints = message.spawnPoints.map((e) => SpawnPoint.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GameStateEvent>, I>>(base?: I): GameStateEvent {
+    return GameStateEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GameStateEvent>, I>>(object: I): GameStateEvent {
+    const message = createBaseGameStateEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.units = object.units?.map((e) => LiveUnit.fromPartial(e)) || [];
+    message.objectives = object.objectives?.map((e) => Objective.fromPartial(e)) || [];
+    message.areas = object.areas?.map((e) => ScenarioArea.fromPartial(e)) || [];
+    message.spawnPoints = object.spawnPoints?.map((e) => SpawnPoint.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseUnitDestroyedEvent(): UnitDestroyedEvent {
+  return { sessionId: "", unitId: "", attackerId: "" };
+}
+
+export const UnitDestroyedEvent: MessageFns<UnitDestroyedEvent> = {
+  encode(message: UnitDestroyedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.attackerId !== "") {
+      writer.uint32(26).string(message.attackerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitDestroyedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitDestroyedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.attackerId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitDestroyedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      attackerId: isSet(object.attackerId) ? globalThis.String(object.attackerId) : "",
+    };
+  },
+
+  toJSON(message: UnitDestroyedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.attackerId !== "") {
+      obj.attackerId = message.attackerId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitDestroyedEvent>, I>>(base?: I): UnitDestroyedEvent {
+    return UnitDestroyedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitDestroyedEvent>, I>>(object: I): UnitDestroyedEvent {
+    const message = createBaseUnitDestroyedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.attackerId = object.attackerId ?? "";
+    return message;
+  },
+};
+
+function createBaseUnitSpottedEvent(): UnitSpottedEvent {
+  return { sessionId: "", unit: undefined };
+}
+
+export const UnitSpottedEvent: MessageFns<UnitSpottedEvent> = {
+  encode(message: UnitSpottedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unit !== undefined) {
+      LiveUnit.encode(message.unit, writer.uint32(18).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitSpottedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitSpottedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unit = LiveUnit.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitSpottedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unit: isSet(object.unit) ? LiveUnit.fromJSON(object.unit) : undefined,
+    };
+  },
+
+  toJSON(message: UnitSpottedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unit !== undefined) {
+      obj.unit = LiveUnit.toJSON(message.unit);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitSpottedEvent>, I>>(base?: I): UnitSpottedEvent {
+    return UnitSpottedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitSpottedEvent>, I>>(object: I): UnitSpottedEvent {
+    const message = createBaseUnitSpottedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unit = (object.unit !== undefined && object.unit !== null)
+      ? LiveUnit.fromPartial(object.unit)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseUnitLostEvent(): UnitLostEvent {
+  return { sessionId: "", unitId: "" };
+}
+
+export const UnitLostEvent: MessageFns<UnitLostEvent> = {
+  encode(message: UnitLostEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitLostEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitLostEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitLostEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+    };
+  },
+
+  toJSON(message: UnitLostEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitLostEvent>, I>>(base?: I): UnitLostEvent {
+    return UnitLostEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitLostEvent>, I>>(object: I): UnitLostEvent {
+    const message = createBaseUnitLostEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    return message;
+  },
+};
+
+function createBaseObjectiveStateChangedEvent(): ObjectiveStateChangedEvent {
+  return {
+    sessionId: "",
+    objectiveId: "",
+    letter: "",
+    state: 0,
+    progress: 0,
+    owner: undefined,
+    capturingSide: undefined,
+  };
+}
+
+export const ObjectiveStateChangedEvent: MessageFns<ObjectiveStateChangedEvent> = {
+  encode(message: ObjectiveStateChangedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.objectiveId !== "") {
+      writer.uint32(18).string(message.objectiveId);
+    }
+    if (message.letter !== "") {
+      writer.uint32(26).string(message.letter);
+    }
+    if (message.state !== 0) {
+      writer.uint32(32).int32(message.state);
+    }
+    if (message.progress !== 0) {
+      writer.uint32(45).float(message.progress);
+    }
+    if (message.owner !== undefined) {
+      writer.uint32(48).int32(message.owner);
+    }
+    if (message.capturingSide !== undefined) {
+      writer.uint32(56).int32(message.capturingSide);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ObjectiveStateChangedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseObjectiveStateChangedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.objectiveId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.letter = reader.string();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.state = reader.int32() as any;
+          continue;
+        }
+        case 5: {
+          if (tag !== 45) {
+            break;
+          }
+
+          message.progress = reader.float();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.owner = reader.int32() as any;
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.capturingSide = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ObjectiveStateChangedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      objectiveId: isSet(object.objectiveId) ? globalThis.String(object.objectiveId) : "",
+      letter: isSet(object.letter) ? globalThis.String(object.letter) : "",
+      state: isSet(object.state) ? objectiveStateFromJSON(object.state) : 0,
+      progress: isSet(object.progress) ? globalThis.Number(object.progress) : 0,
+      owner: isSet(object.owner) ? unitSideFromJSON(object.owner) : undefined,
+      capturingSide: isSet(object.capturingSide) ? unitSideFromJSON(object.capturingSide) : undefined,
+    };
+  },
+
+  toJSON(message: ObjectiveStateChangedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.objectiveId !== "") {
+      obj.objectiveId = message.objectiveId;
+    }
+    if (message.letter !== "") {
+      obj.letter = message.letter;
+    }
+    if (message.state !== 0) {
+      obj.state = objectiveStateToJSON(message.state);
+    }
+    if (message.progress !== 0) {
+      obj.progress = message.progress;
+    }
+    if (message.owner !== undefined) {
+      obj.owner = unitSideToJSON(message.owner);
+    }
+    if (message.capturingSide !== undefined) {
+      obj.capturingSide = unitSideToJSON(message.capturingSide);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ObjectiveStateChangedEvent>, I>>(base?: I): ObjectiveStateChangedEvent {
+    return ObjectiveStateChangedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ObjectiveStateChangedEvent>, I>>(object: I): ObjectiveStateChangedEvent {
+    const message = createBaseObjectiveStateChangedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.objectiveId = object.objectiveId ?? "";
+    message.letter = object.letter ?? "";
+    message.state = object.state ?? 0;
+    message.progress = object.progress ?? 0;
+    message.owner = object.owner ?? undefined;
+    message.capturingSide = object.capturingSide ?? undefined;
+    return message;
+  },
+};
+
+function createBaseCommandRejectedEvent(): CommandRejectedEvent {
+  return { sessionId: "", command: "", reason: 0, message: "" };
+}
+
+export const CommandRejectedEvent: MessageFns<CommandRejectedEvent> = {
+  encode(message: CommandRejectedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.command !== "") {
+      writer.uint32(18).string(message.command);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(24).int32(message.reason);
+    }
+    if (message.message !== "") {
+      writer.uint32(34).string(message.message);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CommandRejectedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCommandRejectedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.command = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.message = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CommandRejectedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      command: isSet(object.command) ? globalThis.String(object.command) : "",
+      reason: isSet(object.reason) ? commandRejectionReasonFromJSON(object.reason) : 0,
+      message: isSet(object.message) ? globalThis.String(object.message) : "",
+    };
+  },
+
+  toJSON(message: CommandRejectedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.command !== "") {
+      obj.command = message.command;
+    }
+    if (message.reason !== 0) {
+      obj.reason = commandRejectionReasonToJSON(message.reason);
+    }
+    if (message.message !== "") {
+      obj.message = message.message;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CommandRejectedEvent>, I>>(base?: I): CommandRejectedEvent {
+    return CommandRejectedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CommandRejectedEvent>, I>>(object: I): CommandRejectedEvent {
+    const message = createBaseCommandRejectedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.command = object.command ?? "";
+    message.reason = object.reason ?? 0;
+    message.message = object.message ?? "";
+    return message;
+  },
+};
+
+function createBaseUnitOrderCancelledEvent(): UnitOrderCancelledEvent {
+  return { sessionId: "", unitId: "", reason: 0, position: undefined };
+}
+
+export const UnitOrderCancelledEvent: MessageFns<UnitOrderCancelledEvent> = {
+  encode(message: UnitOrderCancelledEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(24).int32(message.reason);
+    }
+    if (message.position !== undefined) {
+      Position.encode(message.position, writer.uint32(34).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitOrderCancelledEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitOrderCancelledEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.position = Position.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitOrderCancelledEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      reason: isSet(object.reason) ? orderCancelReasonFromJSON(object.reason) : 0,
+      position: isSet(object.position) ? Position.fromJSON(object.position) : undefined,
+    };
+  },
+
+  toJSON(message: UnitOrderCancelledEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.reason !== 0) {
+      obj.reason = orderCancelReasonToJSON(message.reason);
+    }
+    if (message.position !== undefined) {
+      obj.position = Position.toJSON(message.position);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitOrderCancelledEvent>, I>>(base?: I): UnitOrderCancelledEvent {
+    return UnitOrderCancelledEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitOrderCancelledEvent>, I>>(object: I): UnitOrderCancelledEvent {
+    const message = createBaseUnitOrderCancelledEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.reason = object.reason ?? 0;
+    message.position = (object.position !== undefined && object.position !== null)
+      ? Position.fromPartial(object.position)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseUnitPathUpdatedEvent(): UnitPathUpdatedEvent {
+  return { sessionId: "", unitId: "", remaining: [], startsInSecs: 0 };
+}
+
+export const UnitPathUpdatedEvent: MessageFns<UnitPathUpdatedEvent> = {
+  encode(message: UnitPathUpdatedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    for (const v of message.remaining) {
+      Position.encode(v!, writer.uint32(26).fork()).join();
+    }
+    if (message.startsInSecs !== 0) {
+      writer.uint32(37).float(message.startsInSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitPathUpdatedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitPathUpdatedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.remaining.push(Position.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.startsInSecs = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitPathUpdatedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      remaining: globalThis.Array.isArray(object?.remaining)
+        ? object.remaining.map((e: any) => Position.fromJSON(e))
+        : [],
+      startsInSecs: isSet(object.startsInSecs) ? globalThis.Number(object.startsInSecs) : 0,
+    };
+  },
+
+  toJSON(message: UnitPathUpdatedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.remaining?.length) {
+      obj.remaining = message.remaining.map((e) => Position.toJSON(e));
+    }
+    if (message.startsInSecs !== 0) {
+      obj.startsInSecs = message.startsInSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitPathUpdatedEvent>, I>>(base?: I): UnitPathUpdatedEvent {
+    return UnitPathUpdatedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitPathUpdatedEvent>, I>>(object: I): UnitPathUpdatedEvent {
+    const message = createBaseUnitPathUpdatedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.remaining = object.remaining?.map((e) => Position.fromPartial(e)) || [];
+    message.startsInSecs = object.startsInSecs ?? 0;
+    return message;
+  },
+};
+
+function createBaseFireMissionLaunchedEvent(): FireMissionLaunchedEvent {
+  return { sessionId: "", unitId: "", target: undefined, impactInSecs: 0 };
+}
+
+export const FireMissionLaunchedEvent: MessageFns<FireMissionLaunchedEvent> = {
+  encode(message: FireMissionLaunchedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.target !== undefined) {
+      Position.encode(message.target, writer.uint32(26).fork()).join();
+    }
+    if (message.impactInSecs !== 0) {
+      writer.uint32(37).float(message.impactInSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): FireMissionLaunchedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFireMissionLaunchedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.target = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.impactInSecs = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FireMissionLaunchedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      target: isSet(object.target) ? Position.fromJSON(object.target) : undefined,
+      impactInSecs: isSet(object.impactInSecs) ? globalThis.Number(object.impactInSecs) : 0,
+    };
+  },
+
+  toJSON(message: FireMissionLaunchedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.target !== undefined) {
+      obj.target = Position.toJSON(message.target);
+    }
+    if (message.impactInSecs !== 0) {
+      obj.impactInSecs = message.impactInSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FireMissionLaunchedEvent>, I>>(base?: I): FireMissionLaunchedEvent {
+    return FireMissionLaunchedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FireMissionLaunchedEvent>, I>>(object: I): FireMissionLaunchedEvent {
+    const message = createBaseFireMissionLaunchedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.target = (object.target !== undefined && object.target !== null)
+      ? Position.fromPartial(object.target)
+      : undefined;
+    message.impactInSecs = object.impactInSecs ?? 0;
+    return message;
+  },
+};
+
+function createBaseShellImpactEvent(): ShellImpactEvent {
+  return { sessionId: "", unitId: "", position: undefined, blastRadius: 0 };
+}
+
+export const ShellImpactEvent: MessageFns<ShellImpactEvent> = {
+  encode(message: ShellImpactEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.position !== undefined) {
+      Position.encode(message.position, writer.uint32(26).fork()).join();
+    }
+    if (message.blastRadius !== 0) {
+      writer.uint32(37).float(message.blastRadius);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ShellImpactEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseShellImpactEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.position = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.blastRadius = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ShellImpactEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      position: isSet(object.position) ? Position.fromJSON(object.position) : undefined,
+      blastRadius: isSet(object.blastRadius) ? globalThis.Number(object.blastRadius) : 0,
+    };
+  },
+
+  toJSON(message: ShellImpactEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.position !== undefined) {
+      obj.position = Position.toJSON(message.position);
+    }
+    if (message.blastRadius !== 0) {
+      obj.blastRadius = message.blastRadius;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ShellImpactEvent>, I>>(base?: I): ShellImpactEvent {
+    return ShellImpactEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ShellImpactEvent>, I>>(object: I): ShellImpactEvent {
+    const message = createBaseShellImpactEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.position = (object.position !== undefined && object.position !== null)
+      ? Position.fromPartial(object.position)
+      : undefined;
+    message.blastRadius = object.blastRadius ?? 0;
+    return message;
+  },
+};
+
+function createBaseConstructionStartedEvent(): ConstructionStartedEvent {
+  return { sessionId: "", unitId: "", areaType: "", completeInSecs: 0 };
+}
+
+export const ConstructionStartedEvent: MessageFns<ConstructionStartedEvent> = {
+  encode(message: ConstructionStartedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.areaType !== "") {
+      writer.uint32(26).string(message.areaType);
+    }
+    if (message.completeInSecs !== 0) {
+      writer.uint32(37).float(message.completeInSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ConstructionStartedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseConstructionStartedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.areaType = reader.string();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.completeInSecs = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ConstructionStartedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      areaType: isSet(object.areaType) ? globalThis.String(object.areaType) : "",
+      completeInSecs: isSet(object.completeInSecs) ? globalThis.Number(object.completeInSecs) : 0,
+    };
+  },
+
+  toJSON(message: ConstructionStartedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.areaType !== "") {
+      obj.areaType = message.areaType;
+    }
+    if (message.completeInSecs !== 0) {
+      obj.completeInSecs = message.completeInSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ConstructionStartedEvent>, I>>(base?: I): ConstructionStartedEvent {
+    return ConstructionStartedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ConstructionStartedEvent>, I>>(object: I): ConstructionStartedEvent {
+    const message = createBaseConstructionStartedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.areaType = object.areaType ?? "";
+    message.completeInSecs = object.completeInSecs ?? 0;
+    return message;
+  },
+};
+
+function createBaseConstructionAbandonedEvent(): ConstructionAbandonedEvent {
+  return { sessionId: "", unitId: "", areaType: "" };
+}
+
+export const ConstructionAbandonedEvent: MessageFns<ConstructionAbandonedEvent> = {
+  encode(message: ConstructionAbandonedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.areaType !== "") {
+      writer.uint32(26).string(message.areaType);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ConstructionAbandonedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseConstructionAbandonedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.areaType = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ConstructionAbandonedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      areaType: isSet(object.areaType) ? globalThis.String(object.areaType) : "",
+    };
+  },
+
+  toJSON(message: ConstructionAbandonedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.areaType !== "") {
+      obj.areaType = message.areaType;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ConstructionAbandonedEvent>, I>>(base?: I): ConstructionAbandonedEvent {
+    return ConstructionAbandonedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ConstructionAbandonedEvent>, I>>(object: I): ConstructionAbandonedEvent {
+    const message = createBaseConstructionAbandonedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.areaType = object.areaType ?? "";
+    return message;
+  },
+};
+
+function createBaseAreaCreatedEvent(): AreaCreatedEvent {
+  return { sessionId: "", area: undefined, builtBy: "" };
+}
+
+export const AreaCreatedEvent: MessageFns<AreaCreatedEvent> = {
+  encode(message: AreaCreatedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.area !== undefined) {
+      ScenarioArea.encode(message.area, writer.uint32(18).fork()).join();
+    }
+    if (message.builtBy !== "") {
+      writer.uint32(26).string(message.builtBy);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): AreaCreatedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAreaCreatedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.area = ScenarioArea.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.builtBy = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AreaCreatedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      area: isSet(object.area) ? ScenarioArea.fromJSON(object.area) : undefined,
+      builtBy: isSet(object.builtBy) ? globalThis.String(object.builtBy) : "",
+    };
+  },
+
+  toJSON(message: AreaCreatedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.area !== undefined) {
+      obj.area = ScenarioArea.toJSON(message.area);
+    }
+    if (message.builtBy !== "") {
+      obj.builtBy = message.builtBy;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<AreaCreatedEvent>, I>>(base?: I): AreaCreatedEvent {
+    return AreaCreatedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<AreaCreatedEvent>, I>>(object: I): AreaCreatedEvent {
+    const message = createBaseAreaCreatedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.area = (object.area !== undefined && object.area !== null)
+      ? ScenarioArea.fromPartial(object.area)
+      : undefined;
+    message.builtBy = object.builtBy ?? "";
+    return message;
+  },
+};
+
+function createBaseHeadquartersLostEvent(): HeadquartersLostEvent {
+  return { sessionId: "", side: 0 };
+}
+
+export const HeadquartersLostEvent: MessageFns<HeadquartersLostEvent> = {
+  encode(message: HeadquartersLostEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.side !== 0) {
+      writer.uint32(16).int32(message.side);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): HeadquartersLostEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseHeadquartersLostEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.side = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): HeadquartersLostEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      side: isSet(object.side) ? unitSideFromJSON(object.side) : 0,
+    };
+  },
+
+  toJSON(message: HeadquartersLostEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.side !== 0) {
+      obj.side = unitSideToJSON(message.side);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<HeadquartersLostEvent>, I>>(base?: I): HeadquartersLostEvent {
+    return HeadquartersLostEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<HeadquartersLostEvent>, I>>(object: I): HeadquartersLostEvent {
+    const message = createBaseHeadquartersLostEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.side = object.side ?? 0;
+    return message;
+  },
+};
+
+function createBaseUnitInProduction(): UnitInProduction {
+  return { unitType: "", completeInSecs: 0 };
+}
+
+export const UnitInProduction: MessageFns<UnitInProduction> = {
+  encode(message: UnitInProduction, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.unitType !== "") {
+      writer.uint32(10).string(message.unitType);
+    }
+    if (message.completeInSecs !== 0) {
+      writer.uint32(21).float(message.completeInSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitInProduction {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitInProduction();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.unitType = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 21) {
+            break;
+          }
+
+          message.completeInSecs = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitInProduction {
+    return {
+      unitType: isSet(object.unitType) ? globalThis.String(object.unitType) : "",
+      completeInSecs: isSet(object.completeInSecs) ? globalThis.Number(object.completeInSecs) : 0,
+    };
+  },
+
+  toJSON(message: UnitInProduction): unknown {
+    const obj: any = {};
+    if (message.unitType !== "") {
+      obj.unitType = message.unitType;
+    }
+    if (message.completeInSecs !== 0) {
+      obj.completeInSecs = message.completeInSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitInProduction>, I>>(base?: I): UnitInProduction {
+    return UnitInProduction.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitInProduction>, I>>(object: I): UnitInProduction {
+    const message = createBaseUnitInProduction();
+    message.unitType = object.unitType ?? "";
+    message.completeInSecs = object.completeInSecs ?? 0;
+    return message;
+  },
+};
+
+function createBaseResourcesUpdate(): ResourcesUpdate {
+  return { sessionId: "", supplies: 0, unitsInBuild: 0, production: [] };
+}
+
+export const ResourcesUpdate: MessageFns<ResourcesUpdate> = {
+  encode(message: ResourcesUpdate, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.supplies !== 0) {
+      writer.uint32(16).uint32(message.supplies);
+    }
+    if (message.unitsInBuild !== 0) {
+      writer.uint32(24).uint32(message.unitsInBuild);
+    }
+    for (const v of message.production) {
+      UnitInProduction.encode(v!, writer.uint32(34).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ResourcesUpdate {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseResourcesUpdate();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.supplies = reader.uint32();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.unitsInBuild = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.production.push(UnitInProduction.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ResourcesUpdate {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      supplies: isSet(object.supplies) ? globalThis.Number(object.supplies) : 0,
+      unitsInBuild: isSet(object.unitsInBuild) ? globalThis.Number(object.unitsInBuild) : 0,
+      production: globalThis.Array.isArray(object?.production)
+        ? object.production.map((e: any) => UnitInProduction.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: ResourcesUpdate): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.supplies !== 0) {
+      obj.supplies = Math.round(message.supplies);
+    }
+    if (message.unitsInBuild !== 0) {
+      obj.unitsInBuild = Math.round(message.unitsInBuild);
+    }
+    if (message.production?.length) {
+      obj.production = message.production.map((e) => UnitInProduction.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ResourcesUpdate>, I>>(base?: I): ResourcesUpdate {
+    return ResourcesUpdate.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ResourcesUpdate>, I>>(object: I): ResourcesUpdate {
+    const message = createBaseResourcesUpdate();
+    message.sessionId = object.sessionId ?? "";
+    message.supplies = object.supplies ?? 0;
+    message.unitsInBuild = object.unitsInBuild ?? 0;
+    message.production = object.production?.map((e) => UnitInProduction.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseUnitSpawnedEvent(): UnitSpawnedEvent {
+  return { sessionId: "", unit: undefined };
+}
+
+export const UnitSpawnedEvent: MessageFns<UnitSpawnedEvent> = {
+  encode(message: UnitSpawnedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unit !== undefined) {
+      LiveUnit.encode(message.unit, writer.uint32(18).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitSpawnedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitSpawnedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unit = LiveUnit.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitSpawnedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unit: isSet(object.unit) ? LiveUnit.fromJSON(object.unit) : undefined,
+    };
+  },
+
+  toJSON(message: UnitSpawnedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unit !== undefined) {
+      obj.unit = LiveUnit.toJSON(message.unit);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitSpawnedEvent>, I>>(base?: I): UnitSpawnedEvent {
+    return UnitSpawnedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitSpawnedEvent>, I>>(object: I): UnitSpawnedEvent {
+    const message = createBaseUnitSpawnedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.unit = (object.unit !== undefined && object.unit !== null)
+      ? LiveUnit.fromPartial(object.unit)
+      : undefined;
+    return message;
+  },
+};
+
+function createBasePlayerDisconnectedEvent(): PlayerDisconnectedEvent {
+  return { sessionId: "", userId: "", reconnectWithinSecs: 0 };
+}
+
+export const PlayerDisconnectedEvent: MessageFns<PlayerDisconnectedEvent> = {
+  encode(message: PlayerDisconnectedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.userId !== "") {
+      writer.uint32(18).string(message.userId);
+    }
+    if (message.reconnectWithinSecs !== 0) {
+      writer.uint32(29).float(message.reconnectWithinSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): PlayerDisconnectedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePlayerDisconnectedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.userId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.reconnectWithinSecs = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PlayerDisconnectedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      userId: isSet(object.userId) ? globalThis.String(object.userId) : "",
+      reconnectWithinSecs: isSet(object.reconnectWithinSecs) ? globalThis.Number(object.reconnectWithinSecs) : 0,
+    };
+  },
+
+  toJSON(message: PlayerDisconnectedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.userId !== "") {
+      obj.userId = message.userId;
+    }
+    if (message.reconnectWithinSecs !== 0) {
+      obj.reconnectWithinSecs = message.reconnectWithinSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PlayerDisconnectedEvent>, I>>(base?: I): PlayerDisconnectedEvent {
+    return PlayerDisconnectedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PlayerDisconnectedEvent>, I>>(object: I): PlayerDisconnectedEvent {
+    const message = createBasePlayerDisconnectedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.userId = object.userId ?? "";
+    message.reconnectWithinSecs = object.reconnectWithinSecs ?? 0;
+    return message;
+  },
+};
+
+function createBasePlayerReconnectedEvent(): PlayerReconnectedEvent {
+  return { sessionId: "", userId: "" };
+}
+
+export const PlayerReconnectedEvent: MessageFns<PlayerReconnectedEvent> = {
+  encode(message: PlayerReconnectedEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.userId !== "") {
+      writer.uint32(18).string(message.userId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): PlayerReconnectedEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePlayerReconnectedEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.userId = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PlayerReconnectedEvent {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      userId: isSet(object.userId) ? globalThis.String(object.userId) : "",
+    };
+  },
+
+  toJSON(message: PlayerReconnectedEvent): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.userId !== "") {
+      obj.userId = message.userId;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<PlayerReconnectedEvent>, I>>(base?: I): PlayerReconnectedEvent {
+    return PlayerReconnectedEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<PlayerReconnectedEvent>, I>>(object: I): PlayerReconnectedEvent {
+    const message = createBasePlayerReconnectedEvent();
+    message.sessionId = object.sessionId ?? "";
+    message.userId = object.userId ?? "";
+    return message;
+  },
+};
+
+function createBaseUnitSnapshot(): UnitSnapshot {
+  return { unit: undefined, waypoints: [], orderDelaySecs: 0, movement: undefined };
+}
+
+export const UnitSnapshot: MessageFns<UnitSnapshot> = {
+  encode(message: UnitSnapshot, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.unit !== undefined) {
+      LiveUnit.encode(message.unit, writer.uint32(10).fork()).join();
+    }
+    for (const v of message.waypoints) {
+      Position.encode(v!, writer.uint32(18).fork()).join();
+    }
+    if (message.orderDelaySecs !== 0) {
+      writer.uint32(29).float(message.orderDelaySecs);
+    }
+    if (message.movement !== undefined) {
+      UnitMovementStarted.encode(message.movement, writer.uint32(34).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitSnapshot {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitSnapshot();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.unit = LiveUnit.decode(reader, reader.uint32());
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.waypoints.push(Position.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.orderDelaySecs = reader.float();
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.movement = UnitMovementStarted.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitSnapshot {
+    return {
+      unit: isSet(object.unit) ? LiveUnit.fromJSON(object.unit) : undefined,
+      waypoints: globalThis.Array.isArray(object?.waypoints)
+        ? object.waypoints.map((e: any) => Position.fromJSON(e))
+        : [],
+      orderDelaySecs: isSet(object.orderDelaySecs) ? globalThis.Number(object.orderDelaySecs) : 0,
+      movement: isSet(object.movement) ? UnitMovementStarted.fromJSON(object.movement) : undefined,
+    };
+  },
+
+  toJSON(message: UnitSnapshot): unknown {
+    const obj: any = {};
+    if (message.unit !== undefined) {
+      obj.unit = LiveUnit.toJSON(message.unit);
+    }
+    if (message.waypoints?.length) {
+      obj.waypoints = message.waypoints.map((e) => Position.toJSON(e));
+    }
+    if (message.orderDelaySecs !== 0) {
+      obj.orderDelaySecs = message.orderDelaySecs;
+    }
+    if (message.movement !== undefined) {
+      obj.movement = UnitMovementStarted.toJSON(message.movement);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitSnapshot>, I>>(base?: I): UnitSnapshot {
+    return UnitSnapshot.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitSnapshot>, I>>(object: I): UnitSnapshot {
+    const message = createBaseUnitSnapshot();
+    message.unit = (object.unit !== undefined && object.unit !== null)
+      ? LiveUnit.fromPartial(object.unit)
+      : undefined;
+    message.waypoints = object.waypoints?.map((e) => Position.fromPartial(e)) || [];
+    message.orderDelaySecs = object.orderDelaySecs ?? 0;
+    message.movement = (object.movement !== undefined && object.movement !== null)
+      ? UnitMovementStarted.fromPartial(object.movement)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseObjectiveSnapshot(): ObjectiveSnapshot {
+  return {
+    objectiveId: "",
+    letter: "",
+    position: undefined,
+    state: 0,
+    progress: 0,
+    owner: undefined,
+    capturingSide: undefined,
+  };
+}
+
+export const ObjectiveSnapshot: MessageFns<ObjectiveSnapshot> = {
+  encode(message: ObjectiveSnapshot, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.objectiveId !== "") {
+      writer.uint32(10).string(message.objectiveId);
+    }
+    if (message.letter !== "") {
+      writer.uint32(18).string(message.letter);
+    }
+    if (message.position !== undefined) {
+      Position.encode(message.position, writer.uint32(26).fork()).join();
+    }
+    if (message.state !== 0) {
+      writer.uint32(32).int32(message.state);
+    }
+    if (message.progress !== 0) {
+      writer.uint32(45).float(message.progress);
+    }
+    if (message.owner !== undefined) {
+      writer.uint32(48).int32(message.owner);
+    }
+    if (message.capturingSide !== undefined) {
+      writer.uint32(56).int32(message.capturingSide);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ObjectiveSnapshot {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseObjectiveSnapshot();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.objectiveId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.letter = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.position = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.state = reader.int32() as any;
+          continue;
+        }
+        case 5: {
+          if (tag !== 45) {
+            break;
+          }
+
+          message.progress = reader.float();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.owner = reader.int32() as any;
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.capturingSide = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ObjectiveSnapshot {
+    return {
+      objectiveId: isSet(object.objectiveId) ? globalThis.String(object.objectiveId) : "",
+      letter: isSet(object.letter) ? globalThis.String(object.letter) : "",
+      position: isSet(object.position) ? Position.fromJSON(object.position) : undefined,
+      state: isSet(object.state) ? objectiveStateFromJSON(object.state) : 0,
+      progress: isSet(object.progress) ? globalThis.Number(object.progress) : 0,
+      owner: isSet(object.owner) ? unitSideFromJSON(object.owner) : undefined,
+      capturingSide: isSet(object.capturingSide) ? unitSideFromJSON(object.capturingSide) : undefined,
+    };
+  },
+
+  toJSON(message: ObjectiveSnapshot): unknown {
+    const obj: any = {};
+    if (message.objectiveId !== "") {
+      obj.objectiveId = message.objectiveId;
+    }
+    if (message.letter !== "") {
+      obj.letter = message.letter;
+    }
+    if (message.position !== undefined) {
+      obj.position = Position.toJSON(message.position);
+    }
+    if (message.state !== 0) {
+      obj.state = objectiveStateToJSON(message.state);
+    }
+    if (message.progress !== 0) {
+      obj.progress = message.progress;
+    }
+    if (message.owner !== undefined) {
+      obj.owner = unitSideToJSON(message.owner);
+    }
+    if (message.capturingSide !== undefined) {
+      obj.capturingSide = unitSideToJSON(message.capturingSide);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ObjectiveSnapshot>, I>>(base?: I): ObjectiveSnapshot {
+    return ObjectiveSnapshot.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ObjectiveSnapshot>, I>>(object: I): ObjectiveSnapshot {
+    const message = createBaseObjectiveSnapshot();
+    message.objectiveId = object.objectiveId ?? "";
+    message.letter = object.letter ?? "";
+    message.position = (object.position !== undefined && object.position !== null)
+      ? Position.fromPartial(object.position)
+      : undefined;
+    message.state = object.state ?? 0;
+    message.progress = object.progress ?? 0;
+    message.owner = object.owner ?? undefined;
+    message.capturingSide = object.capturingSide ?? undefined;
+    return message;
+  },
+};
+
+function createBaseGameSnapshot(): GameSnapshot {
+  return {
+    sessionId: "",
+    tick: "0",
+    gameTimeSecs: 0,
+    units: [],
+    objectives: [],
+    resources: undefined,
+    areas: [],
+    spawnPoints: [],
+  };
+}
+
+export const GameSnapshot: MessageFns<GameSnapshot> = {
+  encode(message: GameSnapshot, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.tick !== "0") {
+      writer.uint32(16).uint64(message.tick);
+    }
+    if (message.gameTimeSecs !== 0) {
+      writer.uint32(25).double(message.gameTimeSecs);
+    }
+    for (const v of message.units) {
+      UnitSnapshot.encode(v!, writer.uint32(34).fork()).join();
+    }
+    for (const v of message.objectives) {
+      ObjectiveSnapshot.encode(v!, writer.uint32(42).fork()).join();
+    }
+    if (message.resources !== undefined) {
+      ResourcesUpdate.encode(message.resources, writer.uint32(50).fork()).join();
+    }
+    for (const v of message.areas) {
+      ScenarioArea.encode(v!, writer.uint32(58).fork()).join();
+    }
+    for (const v of message.spawnPoints) {
+      SpawnPoint.encode(v!, writer.uint32(66).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): GameSnapshot {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGameSnapshot();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.tick = reader.uint64().toString();
+          continue;
+        }
+        case 3: {
+          if (tag !== 25) {
+            break;
+          }
+
+          message.gameTimeSecs = reader.double();
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.units.push(UnitSnapshot.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.objectives.push(ObjectiveSnapshot.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.resources = ResourcesUpdate.decode(reader, reader.uint32());
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.areas.push(ScenarioArea.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.spawnPoints.push(SpawnPoint.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GameSnapshot {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      tick: isSet(object.tick) ? globalThis.String(object.tick) : "0",
+      gameTimeSecs: isSet(object.gameTimeSecs) ? globalThis.Number(object.gameTimeSecs) : 0,
+      units: globalThis.Array.isArray(object?.units) ? object.units.map((e: any) => UnitSnapshot.fromJSON(e)) : [],
+      objectives: globalThis.Array.isArray(object?.objectives)
+        ? object.objectives.map((e: any) => ObjectiveSnapshot.fromJSON(e))
+        : [],
+      resources: isSet(object.resources) ? ResourcesUpdate.fromJSON(object.resources) : undefined,
+      areas: globalThis.Array.isArray(object?.areas) ? object.areas.map((e: any) => ScenarioArea.fromJSON(e)) : [],
+      spawnPoints: globalThis.Array.isArray(object?.spawnPoints)
+        ? object.spawnPoints.map((e: any) => SpawnPoint.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: GameSnapshot): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.tick !== "0") {
+      obj.tick = message.tick;
+    }
+    if (message.gameTimeSecs !== 0) {
+      obj.gameTimeSecs = message.gameTimeSecs;
+    }
+    if (message.units?.length) {
+      obj.units = message.units.map((e) => UnitSnapshot.toJSON(e));
+    }
+    if (message.objectives?.length) {
+      obj.objectives = message.objectives.map((e) => ObjectiveSnapshot.toJSON(e));
+    }
+    if (message.resources !== undefined) {
+      obj.resources = ResourcesUpdate.toJSON(message.resources);
+    }
+    if (message.areas?.length) {
+      obj.areas = message.areas.map((e) => ScenarioArea.toJSON(e));
+    }
+    if (message.spawnPoints?.length) {
+      obj.spawnPoints = message.spawnPoints.map((e) => SpawnPoint.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<GameSnapshot>, I>>(base?: I): GameSnapshot {
+    return GameSnapshot.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GameSnapshot>, I>>(object: I): GameSnapshot {
+    const message = createBaseGameSnapshot();
+    message.sessionId = object.sessionId ?? "";
+    message.tick = object.tick ?? "0";
+    message.gameTimeSecs = object.gameTimeSecs ?? 0;
+    message.units = object.units?.map((e) => UnitSnapshot.fromPartial(e)) || [];
+    message.objectives = object.objectives?.map((e) => ObjectiveSnapshot.fromPartial(e)) || [];
+    message.resources = (object.resources !== undefined && object.resources !== null)
+      ? ResourcesUpdate.fromPartial(object.resources)
+      : undefined;
+    message.areas = object.areas?.map((e) => ScenarioArea.fromPartial(e)) || [];
+    message.spawnPoints = object.spawnPoints?.map((e) => SpawnPoint.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseUnitDelta(): UnitDelta {
+  return { unitId: "", morale: undefined, suppression: undefined, routing: undefined, health: undefined };
+}
+
+export const UnitDelta: MessageFns<UnitDelta> = {
+  encode(message: UnitDelta, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.unitId !== "") {
+      writer.uint32(10).string(message.unitId);
+    }
+    if (message.morale !== undefined) {
+      writer.uint32(37).float(message.morale);
+    }
+    if (message.suppression !== undefined) {
+      writer.uint32(45).float(message.suppression);
+    }
+    if (message.routing !== undefined) {
+      writer.uint32(48).bool(message.routing);
+    }
+    if (message.health !== undefined) {
+      writer.uint32(56).uint32(message.health);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitDelta {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitDelta();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.morale = reader.float();
+          continue;
+        }
+        case 5: {
+          if (tag !== 45) {
+            break;
+          }
+
+          message.suppression = reader.float();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.routing = reader.bool();
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.health = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitDelta {
+    return {
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      morale: isSet(object.morale) ? globalThis.Number(object.morale) : undefined,
+      suppression: isSet(object.suppression) ? globalThis.Number(object.suppression) : undefined,
+      routing: isSet(object.routing) ? globalThis.Boolean(object.routing) : undefined,
+      health: isSet(object.health) ? globalThis.Number(object.health) : undefined,
+    };
+  },
+
+  toJSON(message: UnitDelta): unknown {
+    const obj: any = {};
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.morale !== undefined) {
+      obj.morale = message.morale;
+    }
+    if (message.suppression !== undefined) {
+      obj.suppression = message.suppression;
+    }
+    if (message.routing !== undefined) {
+      obj.routing = message.routing;
+    }
+    if (message.health !== undefined) {
+      obj.health = Math.round(message.health);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitDelta>, I>>(base?: I): UnitDelta {
+    return UnitDelta.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitDelta>, I>>(object: I): UnitDelta {
+    const message = createBaseUnitDelta();
+    message.unitId = object.unitId ?? "";
+    message.morale = object.morale ?? undefined;
+    message.suppression = object.suppression ?? undefined;
+    message.routing = object.routing ?? undefined;
+    message.health = object.health ?? undefined;
+    return message;
+  },
+};
+
+function createBaseTickUpdate(): TickUpdate {
+  return { sessionId: "", tick: "0", units: [] };
+}
+
+export const TickUpdate: MessageFns<TickUpdate> = {
+  encode(message: TickUpdate, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.tick !== "0") {
+      writer.uint32(16).uint64(message.tick);
+    }
+    for (const v of message.units) {
+      UnitDelta.encode(v!, writer.uint32(26).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): TickUpdate {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseTickUpdate();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.tick = reader.uint64().toString();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.units.push(UnitDelta.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): TickUpdate {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      tick: isSet(object.tick) ? globalThis.String(object.tick) : "0",
+      units: globalThis.Array.isArray(object?.units) ? object.units.map((e: any) => UnitDelta.fromJSON(e)) : [],
+    };
+  },
+
+  toJSON(message: TickUpdate): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.tick !== "0") {
+      obj.tick = message.tick;
+    }
+    if (message.units?.length) {
+      obj.units = message.units.map((e) => UnitDelta.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<TickUpdate>, I>>(base?: I): TickUpdate {
+    return TickUpdate.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<TickUpdate>, I>>(object: I): TickUpdate {
+    const message = createBaseTickUpdate();
+    message.sessionId = object.sessionId ?? "";
+    message.tick = object.tick ?? "0";
+    message.units = object.units?.map((e) => UnitDelta.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseUnitMovementStarted(): UnitMovementStarted {
+  return { sessionId: "", unitId: "", start: undefined, path: [], speedKmPerSec: 0, startedAtSecs: 0, etaSecs: 0 };
+}
+
+export const UnitMovementStarted: MessageFns<UnitMovementStarted> = {
+  encode(message: UnitMovementStarted, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.sessionId !== "") {
+      writer.uint32(10).string(message.sessionId);
+    }
+    if (message.unitId !== "") {
+      writer.uint32(18).string(message.unitId);
+    }
+    if (message.start !== undefined) {
+      Position.encode(message.start, writer.uint32(26).fork()).join();
+    }
+    for (const v of message.path) {
+      Position.encode(v!, writer.uint32(34).fork()).join();
+    }
+    if (message.speedKmPerSec !== 0) {
+      writer.uint32(41).double(message.speedKmPerSec);
+    }
+    if (message.startedAtSecs !== 0) {
+      writer.uint32(49).double(message.startedAtSecs);
+    }
+    if (message.etaSecs !== 0) {
+      writer.uint32(57).double(message.etaSecs);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): UnitMovementStarted {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUnitMovementStarted();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.unitId = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.start = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.path.push(Position.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 5: {
+          if (tag !== 41) {
+            break;
+          }
+
+          message.speedKmPerSec = reader.double();
+          continue;
+        }
+        case 6: {
+          if (tag !== 49) {
+            break;
+          }
+
+          message.startedAtSecs = reader.double();
+          continue;
+        }
+        case 7: {
+          if (tag !== 57) {
+            break;
+          }
+
+          message.etaSecs = reader.double();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UnitMovementStarted {
+    return {
+      sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
+      unitId: isSet(object.unitId) ? globalThis.String(object.unitId) : "",
+      start: isSet(object.start) ? Position.fromJSON(object.start) : undefined,
+      path: globalThis.Array.isArray(object?.path) ? object.path.map((e: any) => Position.fromJSON(e)) : [],
+      speedKmPerSec: isSet(object.speedKmPerSec) ? globalThis.Number(object.speedKmPerSec) : 0,
+      startedAtSecs: isSet(object.startedAtSecs) ? globalThis.Number(object.startedAtSecs) : 0,
+      etaSecs: isSet(object.etaSecs) ? globalThis.Number(object.etaSecs) : 0,
+    };
+  },
+
+  toJSON(message: UnitMovementStarted): unknown {
+    const obj: any = {};
+    if (message.sessionId !== "") {
+      obj.sessionId = message.sessionId;
+    }
+    if (message.unitId !== "") {
+      obj.unitId = message.unitId;
+    }
+    if (message.start !== undefined) {
+      obj.start = Position.toJSON(message.start);
+    }
+    if (message.path?.length) {
+      obj.path = message.path.map((e) => Position.toJSON(e));
+    }
+    if (message.speedKmPerSec !== 0) {
+      obj.speedKmPerSec = message.speedKmPerSec;
+    }
+    if (message.startedAtSecs !== 0) {
+      obj.startedAtSecs = message.startedAtSecs;
+    }
+    if (message.etaSecs !== 0) {
+      obj.etaSecs = message.etaSecs;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<UnitMovementStarted>, I>>(base?: I): UnitMovementStarted {
+    return UnitMovementStarted.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<UnitMovementStarted>, I>>(object: I): UnitMovementStarted {
+    const message = createBaseUnitMovementStarted();
+    message.sessionId = object.sessionId ?? "";
+    message.unitId = object.unitId ?? "";
+    message.start = (object.start !== undefined && object.start !== null)
+      ? Position.fromPartial(object.start)
+      : undefined;
+    message.path = object.path?.map((e) => Position.fromPartial(e)) || [];
+    message.speedKmPerSec = object.speedKmPerSec ?? 0;
+    message.startedAtSecs = object.startedAtSecs ?? 0;
+    message.etaSecs = object.etaSecs ?? 0;
     return message;
   },
 };
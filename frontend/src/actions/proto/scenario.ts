@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-ts_proto. DO NOT EDIT.
 // versions:
 //   protoc-gen-ts_proto  v2.7.3
-//   protoc               v3.20.3
+//   protoc               v7.36.2
 // source: scenario.proto
 
 /* eslint-disable */
@@ -115,12 +115,40 @@ export interface ScenarioArea {
   coordinates: Ring[];
 }
 
+/**
+ * --- Spawn Point ---
+ * Where a side's produced units may appear, besides its headquarters
+ */
+export interface SpawnPoint {
+  id?: string | undefined;
+  position: Position | undefined;
+  side: UnitSide;
+}
+
+/**
+ * --- Victory Conditions ---
+ * Every enabled condition is checked each tick; the first one met ends the game.
+ * Surrender is always available.
+ */
+export interface VictoryConditions {
+  /** win by holding every objective this long */
+  holdAllObjectivesSecs?: number | undefined;
+  /** win by destroying every enemy unit */
+  destroyAllEnemies: boolean;
+  /** at the limit, the side with more points wins */
+  timeLimitSecs?: number | undefined;
+  /** points per held objective per second */
+  objectivePointsPerSecond: number;
+}
+
 /** --- Scenario Model --- */
 export interface Scenario {
   name?: string | undefined;
   objectives: Objective[];
   units: Unit[];
   areas: ScenarioArea[];
+  victory?: VictoryConditions | undefined;
+  spawnPoints: SpawnPoint[];
 }
 
 /** Create a new scenario */
@@ -618,8 +646,219 @@ export const ScenarioArea: MessageFns<ScenarioArea> = {
   },
 };
 
+function createBaseSpawnPoint(): SpawnPoint {
+  return { id: undefined, position: undefined, side: 0 };
+}
+
+export const SpawnPoint: MessageFns<SpawnPoint> = {
+  encode(message: SpawnPoint, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.id !== undefined) {
+      writer.uint32(10).string(message.id);
+    }
+    if (message.position !== undefined) {
+      Position.encode(message.position, writer.uint32(18).fork()).join();
+    }
+    if (message.side !== 0) {
+      writer.uint32(24).int32(message.side);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SpawnPoint {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSpawnPoint();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = reader.string();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.position = Position.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.side = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SpawnPoint {
+    return {
+      id: isSet(object.id) ? globalThis.String(object.id) : undefined,
+      position: isSet(object.position) ? Position.fromJSON(object.position) : undefined,
+      side: isSet(object.side) ? unitSideFromJSON(object.side) : 0,
+    };
+  },
+
+  toJSON(message: SpawnPoint): unknown {
+    const obj: any = {};
+    if (message.id !== undefined) {
+      obj.id = message.id;
+    }
+    if (message.position !== undefined) {
+      obj.position = Position.toJSON(message.position);
+    }
+    if (message.side !== 0) {
+      obj.side = unitSideToJSON(message.side);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SpawnPoint>, I>>(base?: I): SpawnPoint {
+    return SpawnPoint.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SpawnPoint>, I>>(object: I): SpawnPoint {
+    const message = createBaseSpawnPoint();
+    message.id = object.id ?? undefined;
+    message.position = (object.position !== undefined && object.position !== null)
+      ? Position.fromPartial(object.position)
+      : undefined;
+    message.side = object.side ?? 0;
+    return message;
+  },
+};
+
+function createBaseVictoryConditions(): VictoryConditions {
+  return {
+    holdAllObjectivesSecs: undefined,
+    destroyAllEnemies: false,
+    timeLimitSecs: undefined,
+    objectivePointsPerSecond: 0,
+  };
+}
+
+export const VictoryConditions: MessageFns<VictoryConditions> = {
+  encode(message: VictoryConditions, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.holdAllObjectivesSecs !== undefined) {
+      writer.uint32(13).float(message.holdAllObjectivesSecs);
+    }
+    if (message.destroyAllEnemies !== false) {
+      writer.uint32(16).bool(message.destroyAllEnemies);
+    }
+    if (message.timeLimitSecs !== undefined) {
+      writer.uint32(29).float(message.timeLimitSecs);
+    }
+    if (message.objectivePointsPerSecond !== 0) {
+      writer.uint32(37).float(message.objectivePointsPerSecond);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): VictoryConditions {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseVictoryConditions();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 13) {
+            break;
+          }
+
+          message.holdAllObjectivesSecs = reader.float();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.destroyAllEnemies = reader.bool();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.timeLimitSecs = reader.float();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.objectivePointsPerSecond = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): VictoryConditions {
+    return {
+      holdAllObjectivesSecs: isSet(object.holdAllObjectivesSecs)
+        ? globalThis.Number(object.holdAllObjectivesSecs)
+        : undefined,
+      destroyAllEnemies: isSet(object.destroyAllEnemies) ? globalThis.Boolean(object.destroyAllEnemies) : false,
+      timeLimitSecs: isSet(object.timeLimitSecs) ? globalThis.Number(object.timeLimitSecs) : undefined,
+      objectivePointsPerSecond: isSet(object.objectivePointsPerSecond)
+        ? globalThis.Number(object.objectivePointsPerSecond)
+        : 0,
+    };
+  },
+
+  toJSON(message: VictoryConditions): unknown {
+    const obj: any = {};
+    if (message.holdAllObjectivesSecs !== undefined) {
+      obj.holdAllObjectivesSecs = message.holdAllObjectivesSecs;
+    }
+    if (message.destroyAllEnemies !== false) {
+      obj.destroyAllEnemies = message.destroyAllEnemies;
+    }
+    if (message.timeLimitSecs !== undefined) {
+      obj.timeLimitSecs = message.timeLimitSecs;
+    }
+    if (message.objectivePointsPerSecond !== 0) {
+      obj.objectivePointsPerSecond = message.objectivePointsPerSecond;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<VictoryConditions>, I>>(base?: I): VictoryConditions {
+    return VictoryConditions.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<VictoryConditions>, I>>(object: I): VictoryConditions {
+    const message = createBaseVictoryConditions();
+    message.holdAllObjectivesSecs = object.holdAllObjectivesSecs ?? undefined;
+    message.destroyAllEnemies = object.destroyAllEnemies ?? false;
+    message.timeLimitSecs = object.timeLimitSecs ?? undefined;
+    message.objectivePointsPerSecond = object.objectivePointsPerSecond ?? 0;
+    return message;
+  },
+};
+
 function createBaseScenario(): Scenario {
-  return { name: undefined, objectives: [], units: [], areas: [] };
+  return { name: undefined, objectives: [], units: [], areas: [], victory: undefined, spawnPoints: [] };
 }
 
 export const Scenario: MessageFns<Scenario> = {
@@ -636,6 +875,12 @@ export const Scenario: MessageFns<Scenario> = {
     for (const v of message.areas) {
       ScenarioArea.encode(v!, writer.uint32(34).fork()).join();
     }
+    if (message.victory !== undefined) {
+      VictoryConditions.encode(message.victory, writer.uint32(42).fork()).join();
+    }
+    for (const v of message.spawnPoints) {
+      SpawnPoint.encode(v!, writer.uint32(50).fork()).join();
+    }
     return writer;
   },
 
@@ -678,6 +923,22 @@ export const Scenario: MessageFns<Scenario> = {
           message.areas.push(ScenarioArea.decode(reader, reader.uint32()));
           continue;
         }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.victory = VictoryConditions.decode(reader, reader.uint32());
+          continue;
+        }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.spawnPoints.push(SpawnPoint.decode(reader, reader.uint32()));
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -695,6 +956,10 @@ export const Scenario: MessageFns<Scenario> = {
         : [],
       units: globalThis.Array.isArray(object?.units) ? object.units.map((e: any) => Unit.fromJSON(e)) : [],
       areas: globalThis.Array.isArray(object?.areas) ? object.areas.map((e: any) => ScenarioArea.fromJSON(e)) : [],
+      victory: isSet(object.victory) ? VictoryConditions.fromJSON(object.victory) : undefined,
+      spawnPoints: globalThis.Array.isArray(object?.spawnPoints)
+        ? object.spawnPoints.map((e: any) => SpawnPoint.fromJSON(e))
+        : [],
     };
   },
 
@@ -712,6 +977,12 @@ export const Scenario: MessageFns<Scenario> = {
     if (message.areas?.length) {
       obj.areas = message.areas.map((e) => ScenarioArea.toJSON(e));
     }
+    if (message.victory !== undefined) {
+      obj.victory = VictoryConditions.toJSON(message.victory);
+    }
+    if (message.spawnPoints?.length) {
+      obj.spawnPoints = message.spawnPoints.map((e) => SpawnPoint.toJSON(e));
+    }
     return obj;
   },
 
@@ -724,6 +995,10 @@ export const Scenario: MessageFns<Scenario> = {
     message.objectives = object.objectives?.map((e) => Objective.fromPartial(e)) || [];
     message.units = object.units?.map((e) => Unit.fromPartial(e)) || [];
     message.areas = object.areas?.map((e) => ScenarioArea.fromPartial(e)) || [];
+    message.victory = (object.victory !== undefined && object.victory !== null)
+      ? VictoryConditions.fromPartial(object.victory)
+      : undefined;
+    message.spawnPoints = object.spawnPoints?.map((e) => SpawnPoint.fromPartial(e)) || [];
     return message;
   },
 };
@@ -1,7 +1,7 @@
 // Code generated by protoc-gen-ts_proto. DO NOT EDIT.
 // versions:
 //   protoc-gen-ts_proto  v2.7.3
-//   protoc               v3.20.3
+//   protoc               v7.36.2
 // source: unit_types.proto
 
 /* eslint-disable */
@@ -136,6 +136,50 @@ export interface UnitType {
   sightRange: number;
   movementSpeed: number;
   damage: number;
+  /** e.g., "infantry", "vehicle", "armour" */
+  categories: string[];
+  /** only set for units that can fire missions */
+  fireSupport?: FireSupport | undefined;
+  /** area types the unit can construct, e.g., "Bridge" */
+  builds: string[];
+  /** only set for headquarters */
+  command?: CommandRadius | undefined;
+  /** supplies; unset for units that cannot be produced */
+  cost?: number | undefined;
+  /** seconds */
+  buildTime: number;
+}
+
+/** Effects of a headquarters on the units of its side */
+export interface CommandRadius {
+  /** metres */
+  radius: number;
+  /** for units inside the radius */
+  accuracyModifier: number;
+  /** for units inside the radius */
+  speedModifier: number;
+  /** seconds before units outside the radius start moving */
+  orderDelay: number;
+  /** side-wide once every headquarters is lost */
+  lossAccuracyModifier: number;
+  /** side-wide once every headquarters is lost */
+  lossSpeedModifier: number;
+}
+
+/** Indirect fire profile of a unit type, e.g., artillery or mortars */
+export interface FireSupport {
+  /** metres */
+  minRange: number;
+  /** metres */
+  maxRange: number;
+  /** seconds from firing to impact */
+  flightTime: number;
+  /** metres; shells land up to this far from the aim point */
+  scatter: number;
+  /** metres */
+  blastRadius: number;
+  /** seconds between missions */
+  reloadTime: number;
 }
 
 export interface UnitTypeList {
@@ -153,6 +197,12 @@ function createBaseUnitType(): UnitType {
     sightRange: 0,
     movementSpeed: 0,
     damage: 0,
+    categories: [],
+    fireSupport: undefined,
+    builds: [],
+    command: undefined,
+    cost: undefined,
+    buildTime: 0,
   };
 }
 
@@ -185,6 +235,24 @@ export const UnitType: MessageFns<UnitType> = {
     if (message.damage !== 0) {
       writer.uint32(72).uint32(message.damage);
     }
+    for (const v of message.categories) {
+      writer.uint32(82).string(v!);
+    }
+    if (message.fireSupport !== undefined) {
+      FireSupport.encode(message.fireSupport, writer.uint32(90).fork()).join();
+    }
+    for (const v of message.builds) {
+      writer.uint32(98).string(v!);
+    }
+    if (message.command !== undefined) {
+      CommandRadius.encode(message.command, writer.uint32(106).fork()).join();
+    }
+    if (message.cost !== undefined) {
+      writer.uint32(112).uint32(message.cost);
+    }
+    if (message.buildTime !== 0) {
+      writer.uint32(125).float(message.buildTime);
+    }
     return writer;
   },
 
@@ -267,6 +335,54 @@ export const UnitType: MessageFns<UnitType> = {
           message.damage = reader.uint32();
           continue;
         }
+        case 10: {
+          if (tag !== 82) {
+            break;
+          }
+
+          message.categories.push(reader.string());
+          continue;
+        }
+        case 11: {
+          if (tag !== 90) {
+            break;
+          }
+
+          message.fireSupport = FireSupport.decode(reader, reader.uint32());
+          continue;
+        }
+        case 12: {
+          if (tag !== 98) {
+            break;
+          }
+
+          message.builds.push(reader.string());
+          continue;
+        }
+        case 13: {
+          if (tag !== 106) {
+            break;
+          }
+
+          message.command = CommandRadius.decode(reader, reader.uint32());
+          continue;
+        }
+        case 14: {
+          if (tag !== 112) {
+            break;
+          }
+
+          message.cost = reader.uint32();
+          continue;
+        }
+        case 15: {
+          if (tag !== 125) {
+            break;
+          }
+
+          message.buildTime = reader.float();
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -287,6 +403,14 @@ export const UnitType: MessageFns<UnitType> = {
       sightRange: isSet(object.sightRange) ? globalThis.Number(object.sightRange) : 0,
       movementSpeed: isSet(object.movementSpeed) ? globalThis.Number(object.movementSpeed) : 0,
       damage: isSet(object.damage) ? globalThis.Number(object.damage) : 0,
+      categories: globalThis.Array.isArray(object?.categories)
+        ? object.categories.map((e: any) => globalThis.String(e))
+        : [],
+      fireSupport: isSet(object.fireSupport) ? FireSupport.fromJSON(object.fireSupport) : undefined,
+      builds: globalThis.Array.isArray(object?.builds) ? object.builds.map((e: any) => globalThis.String(e)) : [],
+      command: isSet(object.command) ? CommandRadius.fromJSON(object.command) : undefined,
+      cost: isSet(object.cost) ? globalThis.Number(object.cost) : undefined,
+      buildTime: isSet(object.buildTime) ? globalThis.Number(object.buildTime) : 0,
     };
   },
 
@@ -319,6 +443,24 @@ export const UnitType: MessageFns<UnitType> = {
     if (message.damage !== 0) {
       obj.damage = Math.round(message.damage);
     }
+    if (message.categories?.length) {
+      obj.categories = message.categories;
+    }
+    if (message.fireSupport !== undefined) {
+      obj.fireSupport = FireSupport.toJSON(message.fireSupport);
+    }
+    if (message.builds?.length) {
+      obj.builds = message.builds;
+    }
+    if (message.command !== undefined) {
+      obj.command = CommandRadius.toJSON(message.command);
+    }
+    if (message.cost !== undefined) {
+      obj.cost = Math.round(message.cost);
+    }
+    if (message.buildTime !== 0) {
+      obj.buildTime = message.buildTime;
+    }
     return obj;
   },
 
@@ -336,6 +478,303 @@ export const UnitType: MessageFns<UnitType> = {
     message.sightRange = object.sightRange ?? 0;
     message.movementSpeed = object.movementSpeed ?? 0;
     message.damage = object.damage ?? 0;
+    message.categories = object.categories?.map((e) => e) || [];
+    message.fireSupport = (object.fireSupport !== undefined && object.fireSupport !== null)
+      ? FireSupport.fromPartial(object.fireSupport)
+      : undefined;
+    message.builds = object.builds?.map((e) => e) || [];
+    message.command = (object.command !== undefined && object.command !== null)
+      ? CommandRadius.fromPartial(object.command)
+      : undefined;
+    message.cost = object.cost ?? undefined;
+    message.buildTime = object.buildTime ?? 0;
+    return message;
+  },
+};
+
+function createBaseCommandRadius(): CommandRadius {
+  return {
+    radius: 0,
+    accuracyModifier: 0,
+    speedModifier: 0,
+    orderDelay: 0,
+    lossAccuracyModifier: 0,
+    lossSpeedModifier: 0,
+  };
+}
+
+export const CommandRadius: MessageFns<CommandRadius> = {
+  encode(message: CommandRadius, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.radius !== 0) {
+      writer.uint32(13).float(message.radius);
+    }
+    if (message.accuracyModifier !== 0) {
+      writer.uint32(21).float(message.accuracyModifier);
+    }
+    if (message.speedModifier !== 0) {
+      writer.uint32(29).float(message.speedModifier);
+    }
+    if (message.orderDelay !== 0) {
+      writer.uint32(37).float(message.orderDelay);
+    }
+    if (message.lossAccuracyModifier !== 0) {
+      writer.uint32(45).float(message.lossAccuracyModifier);
+    }
+    if (message.lossSpeedModifier !== 0) {
+      writer.uint32(53).float(message.lossSpeedModifier);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CommandRadius {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCommandRadius();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 13) {
+            break;
+          }
+
+          message.radius = reader.float();
+          continue;
+        }
+        case 2: {
+          if (tag !== 21) {
+            break;
+          }
+
+          message.accuracyModifier = reader.float();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.speedModifier = reader.float();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.orderDelay = reader.float();
+          continue;
+        }
+        case 5: {
+          if (tag !== 45) {
+            break;
+          }
+
+          message.lossAccuracyModifier = reader.float();
+          continue;
+        }
+        case 6: {
+          if (tag !== 53) {
+            break;
+          }
+
+          message.lossSpeedModifier = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CommandRadius {
+    return {
+      radius: isSet(object.radius) ? globalThis.Number(object.radius) : 0,
+      accuracyModifier: isSet(object.accuracyModifier) ? globalThis.Number(object.accuracyModifier) : 0,
+      speedModifier: isSet(object.speedModifier) ? globalThis.Number(object.speedModifier) : 0,
+      orderDelay: isSet(object.orderDelay) ? globalThis.Number(object.orderDelay) : 0,
+      lossAccuracyModifier: isSet(object.lossAccuracyModifier) ? globalThis.Number(object.lossAccuracyModifier) : 0,
+      lossSpeedModifier: isSet(object.lossSpeedModifier) ? globalThis.Number(object.lossSpeedModifier) : 0,
+    };
+  },
+
+  toJSON(message: CommandRadius): unknown {
+    const obj: any = {};
+    if (message.radius !== 0) {
+      obj.radius = message.radius;
+    }
+    if (message.accuracyModifier !== 0) {
+      obj.accuracyModifier = message.accuracyModifier;
+    }
+    if (message.speedModifier !== 0) {
+      obj.speedModifier = message.speedModifier;
+    }
+    if (message.orderDelay !== 0) {
+      obj.orderDelay = message.orderDelay;
+    }
+    if (message.lossAccuracyModifier !== 0) {
+      obj.lossAccuracyModifier = message.lossAccuracyModifier;
+    }
+    if (message.lossSpeedModifier !== 0) {
+      obj.lossSpeedModifier = message.lossSpeedModifier;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CommandRadius>, I>>(base?: I): CommandRadius {
+    return CommandRadius.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CommandRadius>, I>>(object: I): CommandRadius {
+    const message = createBaseCommandRadius();
+    message.radius = object.radius ?? 0;
+    message.accuracyModifier = object.accuracyModifier ?? 0;
+    message.speedModifier = object.speedModifier ?? 0;
+    message.orderDelay = object.orderDelay ?? 0;
+    message.lossAccuracyModifier = object.lossAccuracyModifier ?? 0;
+    message.lossSpeedModifier = object.lossSpeedModifier ?? 0;
+    return message;
+  },
+};
+
+function createBaseFireSupport(): FireSupport {
+  return { minRange: 0, maxRange: 0, flightTime: 0, scatter: 0, blastRadius: 0, reloadTime: 0 };
+}
+
+export const FireSupport: MessageFns<FireSupport> = {
+  encode(message: FireSupport, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.minRange !== 0) {
+      writer.uint32(13).float(message.minRange);
+    }
+    if (message.maxRange !== 0) {
+      writer.uint32(21).float(message.maxRange);
+    }
+    if (message.flightTime !== 0) {
+      writer.uint32(29).float(message.flightTime);
+    }
+    if (message.scatter !== 0) {
+      writer.uint32(37).float(message.scatter);
+    }
+    if (message.blastRadius !== 0) {
+      writer.uint32(45).float(message.blastRadius);
+    }
+    if (message.reloadTime !== 0) {
+      writer.uint32(53).float(message.reloadTime);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): FireSupport {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseFireSupport();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 13) {
+            break;
+          }
+
+          message.minRange = reader.float();
+          continue;
+        }
+        case 2: {
+          if (tag !== 21) {
+            break;
+          }
+
+          message.maxRange = reader.float();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.flightTime = reader.float();
+          continue;
+        }
+        case 4: {
+          if (tag !== 37) {
+            break;
+          }
+
+          message.scatter = reader.float();
+          continue;
+        }
+        case 5: {
+          if (tag !== 45) {
+            break;
+          }
+
+          message.blastRadius = reader.float();
+          continue;
+        }
+        case 6: {
+          if (tag !== 53) {
+            break;
+          }
+
+          message.reloadTime = reader.float();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): FireSupport {
+    return {
+      minRange: isSet(object.minRange) ? globalThis.Number(object.minRange) : 0,
+      maxRange: isSet(object.maxRange) ? globalThis.Number(object.maxRange) : 0,
+      flightTime: isSet(object.flightTime) ? globalThis.Number(object.flightTime) : 0,
+      scatter: isSet(object.scatter) ? globalThis.Number(object.scatter) : 0,
+      blastRadius: isSet(object.blastRadius) ? globalThis.Number(object.blastRadius) : 0,
+      reloadTime: isSet(object.reloadTime) ? globalThis.Number(object.reloadTime) : 0,
+    };
+  },
+
+  toJSON(message: FireSupport): unknown {
+    const obj: any = {};
+    if (message.minRange !== 0) {
+      obj.minRange = message.minRange;
+    }
+    if (message.maxRange !== 0) {
+      obj.maxRange = message.maxRange;
+    }
+    if (message.flightTime !== 0) {
+      obj.flightTime = message.flightTime;
+    }
+    if (message.scatter !== 0) {
+      obj.scatter = message.scatter;
+    }
+    if (message.blastRadius !== 0) {
+      obj.blastRadius = message.blastRadius;
+    }
+    if (message.reloadTime !== 0) {
+      obj.reloadTime = message.reloadTime;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<FireSupport>, I>>(base?: I): FireSupport {
+    return FireSupport.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<FireSupport>, I>>(object: I): FireSupport {
+    const message = createBaseFireSupport();
+    message.minRange = object.minRange ?? 0;
+    message.maxRange = object.maxRange ?? 0;
+    message.flightTime = object.flightTime ?? 0;
+    message.scatter = object.scatter ?? 0;
+    message.blastRadius = object.blastRadius ?? 0;
+    message.reloadTime = object.reloadTime ?? 0;
     return message;
   },
 };
@@ -24,6 +24,7 @@ import { useSocketStore } from "@/integrations/stores/useSocketStore";
 import { canControlUnit } from "@/utils/canControlUnits";
 import { createAreaStyleFactory } from "@/utils/createAreaStyleFactory";
 import { getUnitStyle } from "@/utils/renderEntity";
+import { positionAt } from "@/utils/unitMotion";
 
 interface GameMapPreviewProps {
 	scenario: Scenario,
@@ -61,7 +62,6 @@ export function GameMapPreview({
 	const featureSource = useRef(new VectorSource());
 	const selectedFeatureRef = useRef<any>(null);
 	const selectedUnitRef = useRef<Unit | null>(null);
-	const movedUnits = useSocketStore((s) => s.movedUnits);
 	const getAreaStyle = useRef(
 		createAreaStyleFactory(
 			areaTypes.map((a) => ({
@@ -79,24 +79,28 @@ export function GameMapPreview({
 		selectedUnitRef.current = selectedUnit ?? null;
 	}, [selectedUnit]);
 
-	// Update positions of units if moved
+	// Move units along their last reported movement every frame
 	useEffect(() => {
-		if (!mapInstance.current) return;
-
-		for (const [unitId, { lat, lon }] of Object.entries(movedUnits)) {
-			const feature = featureSource.current
-				.getFeatures()
-				.find((f) => f.get("unitId") === unitId);
-
-			if (feature) {
-				const geometry = feature.getGeometry();
-				if (geometry instanceof Point) {
-					geometry.setCoordinates(fromLonLat([lon, lat]));
-					feature.changed();
+		let frame = requestAnimationFrame(function animate() {
+			const { motions, gameTimeNow } = useSocketStore.getState();
+			const now = gameTimeNow();
+
+			if (now !== null) {
+				for (const feature of featureSource.current.getFeatures()) {
+					const motion = motions[feature.get("unitId")];
+					const position = motion && positionAt(motion, now);
+					const geometry = feature.getGeometry();
+
+					if (position && geometry instanceof Point) {
+						geometry.setCoordinates(fromLonLat([position.lon, position.lat]));
+					}
 				}
 			}
-		}
-	}, [movedUnits]);
+			frame = requestAnimationFrame(animate);
+		});
+
+		return () => cancelAnimationFrame(frame);
+	}, []);
 
 	// Initialize map
 	useEffect(() => {
@@ -230,6 +234,7 @@ export function GameMapPreview({
 					unitId: unit.id ?? "",
 					targetLat: lat,
 					targetLon: lon,
+					fastestRoute: false,
 				};
 				const message: WsClientMessage = { moveUnit: moveReq };
 				const encoded = WsClientMessage.encode(message).finish();
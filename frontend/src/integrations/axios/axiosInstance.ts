@@ -12,11 +12,11 @@ export const axiosInstance = axios.create({
 
 // Set up request interceptor
 axiosInstance.interceptors.request.use((config) => {
-	// Get userId without hook
-	const userId = useSocketStore.getState().userId;
+	// Get the guest token without hook
+	const token = useSocketStore.getState().token;
 
-	if (userId) {
-		config.headers["x-user-id"] = userId; // Custom header
+	if (token) {
+		config.headers.Authorization = `Bearer ${token}`;
 	}
 
 	return config;
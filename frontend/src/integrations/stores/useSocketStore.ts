@@ -1,40 +1,136 @@
 // useSocketStore.ts
 import { create } from "zustand";
 
+import { GuestAuthResponse } from "@/actions/proto/auth";
 import {
 	GameEndedEvent,
+	GameSnapshot,
 	SessionReadyEvent,
+	TickUpdate,
+	UnitMovementStarted,
+	WsClientMessage,
 	WsServerMessage,
 } from "@/actions/proto/game_session";
 
 type Status = "idle" | "connecting" | "connected" | "disconnected" | "error";
 
+export interface UnitStatus {
+	health?: number,
+	morale?: number,
+	suppression?: number,
+	routing?: boolean,
+}
+
+const TOKEN_STORAGE_KEY = "guestToken";
+
+// Reuses the stored guest token so a reload keeps the same identity
+async function guestToken(): Promise<string> {
+	const stored = localStorage.getItem(TOKEN_STORAGE_KEY);
+	if (stored) return stored;
+
+	const res = await fetch("http://localhost:9999/api/auth/guest", { method: "POST" });
+	if (!res.ok) throw new Error(`Guest auth failed: ${res.status}`);
+
+	const { token } = GuestAuthResponse.decode(new Uint8Array(await res.arrayBuffer()));
+	localStorage.setItem(TOKEN_STORAGE_KEY, token);
+
+	return token;
+}
+
+// Each unit's last reported movement, keyed by unit id; stationary units have an empty path
+function snapshotMotions(snapshot: GameSnapshot): Record<string, UnitMovementStarted> {
+	const motions: Record<string, UnitMovementStarted> = {};
+	for (const { unit, movement } of snapshot.units) {
+		if (!unit) continue;
+
+		motions[unit.unitId] = movement ?? UnitMovementStarted.fromPartial({
+			sessionId: snapshot.sessionId,
+			unitId: unit.unitId,
+			start: unit.position,
+			startedAtSecs: snapshot.gameTimeSecs,
+		});
+	}
+
+	return motions;
+}
+
+function snapshotStatus(snapshot: GameSnapshot): Record<string, UnitStatus> {
+	const status: Record<string, UnitStatus> = {};
+	for (const { unit } of snapshot.units) {
+		if (!unit) continue;
+
+		const { health, morale, suppression, routing } = unit;
+		status[unit.unitId] = { health, morale, suppression, routing };
+	}
+
+	return status;
+}
+
+// Unset delta fields are unchanged, so only the set ones overwrite what we had
+function applyTickUpdate(status: Record<string, UnitStatus>, update: TickUpdate): Record<string, UnitStatus> {
+	const next = { ...status };
+	for (const { unitId, ...fields } of update.units) {
+		const changed = Object.fromEntries(Object.entries(fields).filter(([, value]) => value !== undefined));
+		next[unitId] = { ...next[unitId], ...changed };
+	}
+
+	return next;
+}
+
 interface SocketStore {
 	socket: WebSocket | null,
 	userId: string | null,
+	token: string | null,
 	status: Status,
 	sessionReady: SessionReadyEvent | null,
 	gameStartedSessionId: string | null,
 	gameEnded: GameEndedEvent | null,
-	movedUnits: Record<string, { lat: number, lon: number }>,
-	connect: ()=> void,
+	motions: Record<string, UnitMovementStarted>,
+	unitStatus: Record<string, UnitStatus>,
+	// Server game time of the last game frame and when it arrived, in performance.now() ms
+	clock: { gameTimeSecs: number, receivedAt: number } | null,
+	gameTimeNow: ()=> number | null,
+	connect: ()=> Promise<void>,
 }
 
 export const useSocketStore = create<SocketStore>((set, get) => ({
 	socket: null,
 	userId: null,
+	token: null,
 	status: "idle",
 	sessionReady: null,
 	gameStartedSessionId: null,
 	gameEnded: null,
-	movedUnits: {},
-	connect: () => {
-		if (get().socket) return;
+	motions: {},
+	unitStatus: {},
+	clock: null,
+	gameTimeNow: () => {
+		const clock = get().clock;
+		if (!clock) return null;
+
+		return clock.gameTimeSecs + (performance.now() - clock.receivedAt) / 1000;
+	},
+	connect: async () => {
+		if (get().socket || get().status === "connecting") return;
 
 		set({ status: "connecting" });
 
-		const ws = new WebSocket("ws://localhost:9999/ws");
+		let token: string;
+		try {
+			token = await guestToken();
+		} catch (err) {
+			console.error(err);
+			set({ status: "error" });
+
+			return;
+		}
+		set({ token });
+
+		const ws = new WebSocket(`ws://localhost:9999/ws?token=${encodeURIComponent(token)}`);
 		let hasConnected = false;
+		// Sequence of the last game frame; null after a gap until a snapshot restarts the count
+		let lastSequence: number | null = 0;
+		let gameSessionId = "";
 
 		ws.binaryType = "arraybuffer";
 
@@ -52,6 +148,27 @@ export const useSocketStore = create<SocketStore>((set, get) => ({
 
 			try {
 				const msg = WsServerMessage.decode(new Uint8Array(event.data));
+				const sequence = Number(msg.sequence);
+
+				gameSessionId = msg.gameStarted?.sessionId
+					?? msg.gameSnapshot?.sessionId
+					?? msg.tickUpdate?.sessionId
+					?? gameSessionId;
+
+				if (msg.gameSnapshot) {
+					lastSequence = sequence;
+				} else if (sequence > 0 && lastSequence !== null) {
+					if (sequence !== lastSequence + 1) {
+						// Frames went missing; wait for a fresh snapshot before trusting deltas again
+						lastSequence = null;
+						const request = WsClientMessage.fromPartial({
+							requestSnapshot: { sessionId: gameSessionId },
+						});
+						ws.send(WsClientMessage.encode(request).finish());
+					} else {
+						lastSequence = sequence;
+					}
+				}
 
 				if (msg.sessionReady) {
 					set({ sessionReady: msg.sessionReady });
@@ -65,12 +182,28 @@ export const useSocketStore = create<SocketStore>((set, get) => ({
 					set({ gameEnded: msg.gameEnded });
 				}
 
-				if (msg.unitMoved) {
-					const { unitId, targetLat, targetLon } = msg.unitMoved;
+				if (msg.gameTimeSecs > 0) {
+					set({ clock: { gameTimeSecs: msg.gameTimeSecs, receivedAt: performance.now() } });
+				}
+
+				if (msg.gameSnapshot) {
+					set({
+						motions: snapshotMotions(msg.gameSnapshot),
+						unitStatus: snapshotStatus(msg.gameSnapshot),
+					});
+				}
+
+				if (msg.tickUpdate) {
+					const update = msg.tickUpdate;
+					set((state) => ({ unitStatus: applyTickUpdate(state.unitStatus, update) }));
+				}
+
+				if (msg.unitMovementStarted) {
+					const motion = msg.unitMovementStarted;
 					set((state) => ({
-						movedUnits: {
-							...state.movedUnits,
-							[unitId]: { lat: targetLat, lon: targetLon },
+						motions: {
+							...state.motions,
+							[motion.unitId]: motion,
 						},
 					}));
 				}
@@ -84,6 +217,8 @@ export const useSocketStore = create<SocketStore>((set, get) => ({
 			set({ socket: null });
 
 			if (!hasConnected) {
+				// The server forgot the token; get a new identity next time
+				localStorage.removeItem(TOKEN_STORAGE_KEY);
 				set({ status: "error" });
 			} else {
 				set({ status: "disconnected" });
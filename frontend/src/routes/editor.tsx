@@ -320,6 +320,7 @@ export default function ScenarioEditor() {
 				})),
 				units,
 				areas,
+				spawnPoints: [],
 			},
 
 		};
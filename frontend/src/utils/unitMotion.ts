@@ -0,0 +1,39 @@
+import type { UnitMovementStarted } from "@/actions/proto/game_session";
+import type { Position } from "@/actions/proto/scenario";
+
+const EARTH_RADIUS_KM = 6371;
+
+const toRadians = (deg: number) => deg * Math.PI / 180;
+
+function distanceKm(a: Position, b: Position): number {
+	const dLat = toRadians(b.lat - a.lat);
+	const dLon = toRadians(b.lon - a.lon);
+	const h = Math.sin(dLat / 2) ** 2
+		+ Math.cos(toRadians(a.lat)) * Math.cos(toRadians(b.lat)) * Math.sin(dLon / 2) ** 2;
+
+	return 2 * EARTH_RADIUS_KM * Math.atan2(Math.sqrt(h), Math.sqrt(1 - h));
+}
+
+// Where the server puts a unit at the given game time, following the same rule:
+// constant speed along the path from `startedAtSecs` on
+export function positionAt(motion: UnitMovementStarted, gameTimeSecs: number): Position | null {
+	if (!motion.start) return null;
+
+	let budgetKm = motion.speedKmPerSec * Math.max(gameTimeSecs - motion.startedAtSecs, 0);
+	let at = motion.start;
+	for (const next of motion.path) {
+		const legKm = distanceKm(at, next);
+		if (legKm > budgetKm) {
+			const t = budgetKm / legKm;
+
+			return {
+				lat: at.lat + (next.lat - at.lat) * t,
+				lon: at.lon + (next.lon - at.lon) * t,
+			};
+		}
+		budgetKm -= legKm;
+		at = next;
+	}
+
+	return at;
+}